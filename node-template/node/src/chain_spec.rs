@@ -1,12 +1,14 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
+	pallet_ropasci::{GameConfig, Hand},
+	AccountId, AssetId, AssetsConfig, AuraConfig, Balance, BalancesConfig, BlockNumber,
+	GenesisConfig, GrandpaConfig, Hash, Permill, RoPaSciConfig, Signature, SudoConfig,
 	SystemConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{sr25519, Pair, Public};
 use sp_finality_grandpa::AuthorityId as GrandpaId;
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_runtime::traits::{BlakeTwo256, Hash as HashT, IdentifyAccount, Verify};
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -59,6 +61,71 @@ pub fn development_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				ropasci_config(vec![]),
+				true,
+			)
+		},
+		// Bootnodes
+		vec![],
+		// Telemetry
+		None,
+		// Protocol ID
+		None,
+		None,
+		// Properties
+		None,
+		// Extensions
+		None,
+	))
+}
+
+/// Development chain with RoPaSci demo games in "betting" stage ready to be revealed.
+///
+/// The reveal of every demo move is the hand byte followed by the lowercase name of the player as
+/// the salt, e.g. `0x00616c696365` (Rock, "alice") for the game owned by Alice.
+pub fn ropasci_development_config() -> Result<ChainSpec, String> {
+	let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+	Ok(ChainSpec::from_genesis(
+		// Name
+		"RoPaSci Development",
+		// ID
+		"ropasci_dev",
+		ChainType::Development,
+		move || {
+			let alice = get_account_id_from_seed::<sr25519::Public>("Alice");
+			let bob = get_account_id_from_seed::<sr25519::Public>("Bob");
+			let charlie = get_account_id_from_seed::<sr25519::Public>("Charlie");
+			let dave = get_account_id_from_seed::<sr25519::Public>("Dave");
+
+			testnet_genesis(
+				wasm_binary,
+				// Initial PoA authorities
+				vec![authority_keys_from_seed("Alice")],
+				// Sudo account
+				alice.clone(),
+				// Pre-funded accounts
+				vec![alice.clone(), bob.clone(), charlie.clone(), dave.clone()],
+				// Demo games
+				ropasci_config(vec![
+					(
+						alice.clone(),
+						demo_move(Hand::Rock, "alice"),
+						DEMO_ROUND_LENGTH,
+						DEMO_BET,
+						vec![(bob.clone(), demo_move(Hand::Paper, "bob"))],
+					),
+					(
+						charlie.clone(),
+						demo_move(Hand::Scissors, "charlie"),
+						DEMO_ROUND_LENGTH,
+						DEMO_BET,
+						vec![
+							(alice, demo_move(Hand::Scissors, "alice")),
+							(dave, demo_move(Hand::Paper, "dave")),
+						],
+					),
+				]),
 				true,
 			)
 		},
@@ -107,6 +174,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				ropasci_config(vec![]),
 				true,
 			)
 		},
@@ -130,6 +198,7 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	ro_pa_sci: RoPaSciConfig,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -149,8 +218,40 @@ fn testnet_genesis(
 		},
 		sudo: SudoConfig {
 			// Assign network admin rights.
-			key: Some(root_key),
+			key: Some(root_key.clone()),
 		},
 		transaction_payment: Default::default(),
+		assets: AssetsConfig {
+			assets: vec![(DEMO_ASSET, root_key.clone(), true, 1)],
+			metadata: vec![(DEMO_ASSET, b"Demo Token".to_vec(), b"DEMO".to_vec(), 12)],
+			accounts: endowed_accounts.iter().cloned().map(|k| (DEMO_ASSET, k, 1 << 50)).collect(),
+		},
+		ro_pa_sci,
+	}
+}
+
+/// Asset the endowed accounts can bet in besides the native token.
+const DEMO_ASSET: AssetId = 1;
+
+/// Round length of the demo games in blocks.
+const DEMO_ROUND_LENGTH: BlockNumber = 20;
+
+/// Bet of the demo games.
+const DEMO_BET: Balance = 1 << 40;
+
+/// Commitment of a demo move salted with the given name.
+fn demo_move(hand: Hand, salt: &str) -> Hash {
+	let mut reveal = vec![hand as u8];
+	reveal.extend_from_slice(salt.as_bytes());
+	BlakeTwo256::hash(&reveal)
+}
+
+/// RoPaSci genesis with the default game parameters and the given games.
+fn ropasci_config(
+	games: Vec<(AccountId, Hash, BlockNumber, Balance, Vec<(AccountId, Hash)>)>,
+) -> RoPaSciConfig {
+	RoPaSciConfig {
+		config: GameConfig { rake: Permill::from_percent(2), ..Default::default() },
+		games,
 	}
 }
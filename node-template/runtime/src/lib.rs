@@ -11,12 +11,14 @@ pub use frame_support::{
 	construct_runtime, parameter_types,
 	StorageValue,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem,
+		Randomness, StorageInfo,
     },
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
     },
+	PalletId,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -32,7 +34,7 @@ use sp_runtime::{
 	ApplyExtrinsicResult, create_runtime_str, generic,
 	impl_opaque_keys,
 	MultiSignature,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify}, transaction_validity::{TransactionSource, TransactionValidity},
+	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify}, transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 };
 pub use sp_runtime::{Perbill, Permill};
 #[cfg(any(feature = "std", test))]
@@ -268,14 +270,98 @@ impl pallet_sudo::Config for Runtime {
     type Call = Call;
 }
 
+/// Identifier of an asset.
+pub type AssetId = u32;
+
+impl pallet_assets::Config for Runtime {
+    type Event = Event;
+    type Balance = Balance;
+    type AssetId = AssetId;
+    type Currency = Balances;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<{ 1000 * EXISTENTIAL_DEPOSIT }>;
+    type AssetAccountDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type MetadataDepositBase = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type MetadataDepositPerByte = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type ApprovalDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+}
+
+/// Identifier of an item collection.
+pub type CollectionId = u32;
+
+/// Identifier of an item within its collection.
+pub type ItemId = u32;
+
+impl pallet_uniques::Config for Runtime {
+    type Event = Event;
+    type CollectionId = CollectionId;
+    type ItemId = ItemId;
+    type Currency = Balances;
+    type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+    type Locker = ();
+    type CollectionDeposit = ConstU128<{ 1000 * EXISTENTIAL_DEPOSIT }>;
+    type ItemDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type MetadataDepositBase = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type AttributeDepositBase = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type DepositPerByte = ConstU128<EXISTENTIAL_DEPOSIT>;
+    type StringLimit = ConstU32<50>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<64>;
+    type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const SeasonRakeShare: Permill = Permill::from_percent(50);
+	pub const RoPaSciPalletId: PalletId = PalletId(*b"ropasci!");
+	pub const HouseEdge: Permill = Permill::from_percent(5);
+	pub const JackpotShare: Permill = Permill::from_percent(1);
+	pub const UnsignedRevealPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_ropasci::Config for Runtime {
     type Event = Event;
     type MoveHash = Hash;
     type MoveHasher = BlakeTwo256;
     type Currency = Balances;
-    type MinRoundLength = ConstU32<10>;
-    type MaxRoundLength = ConstU32<50>;
+    type Assets = Assets;
+    type Items = Uniques;
+    type PalletId = RoPaSciPalletId;
+    type GameDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type ResultsRetention = ConstU32<DAYS>;
+    type RatingKFactor = ConstU32<16>;
+    type ProvisionalKFactor = ConstU32<40>;
+    type ProvisionalGames = ConstU32<20>;
+    type SeasonRakeShare = SeasonRakeShare;
+    type SeasonLength = ConstU32<{ 30 * DAYS }>;
+    type LeaderboardSize = ConstU32<10>;
+    type SeasonClearLimit = ConstU32<100>;
+    type Randomness = RandomnessCollectiveFlip;
+    type HouseEdge = HouseEdge;
+    // the collective flip randomness is only secure once more than 81 blocks passed
+    type HouseDrawDelay = ConstU32<100>;
+    type HouseRoundLength = ConstU32<HOURS>;
+    type RematchWindow = ConstU32<{ 10 * MINUTES }>;
+    type MaxScheduleRepeats = ConstU32<30>;
+    type ScheduleDeposit = ConstU128<{ 100 * EXISTENTIAL_DEPOSIT }>;
+    type MaxSchedulesPerBlock = ConstU32<50>;
+    type ChallengeRevealLength = ConstU32<DAYS>;
+    type ChallengeCancelDelay = ConstU32<{ 10 * MINUTES }>;
+    type JackpotShare = JackpotShare;
+    type JackpotMinPlayers = ConstU32<5>;
+    type MaxSideBets = ConstU32<100>;
+    type Signature = Signature;
+    type Signer = <Signature as Verify>::Signer;
+    type UnsignedRevealPriority = UnsignedRevealPriority;
+    type MaxActiveGamesPerAccount = ConstU32<10>;
+    type SpendingPeriod = ConstU32<DAYS>;
+    type OnGameEnded = ();
 	type WeightInfo = ();
 }
 
@@ -295,6 +381,8 @@ construct_runtime!(
 		Balances: pallet_balances,
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
+		Assets: pallet_assets,
+		Uniques: pallet_uniques,
 		// Include the custom logic from the pallet-template in the runtime.
 		RoPaSci: pallet_ropasci,
 	}
@@ -335,8 +423,10 @@ mod benches {
     define_benchmarks!(
 		[frame_benchmarking, BaselineBench::<Runtime>]
 		[frame_system, SystemBench::<Runtime>]
+		[pallet_assets, Assets]
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
+		[pallet_uniques, Uniques]
 		[pallet_ropasci, RoPaSci]
 	);
 }
@@ -489,6 +579,52 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_ropasci_runtime_api::RoPaSciApi<Block, Hash, AccountId, BlockNumber, Balance, AssetId> for Runtime {
+		fn game(game_id: Hash) -> Option<pallet_ropasci::Game<BlockNumber, Balance>> {
+			RoPaSci::game(game_id)
+		}
+
+		fn open_games(offset: u32, limit: u32) -> Vec<(Hash, pallet_ropasci::Game<BlockNumber, Balance>)> {
+			RoPaSci::open_games(offset, limit)
+		}
+
+		fn games_of(account: AccountId) -> Vec<Hash> {
+			RoPaSci::games_of(account)
+		}
+
+		fn player_move_status(game_id: Hash, account: AccountId) -> Option<pallet_ropasci::MoveStatus> {
+			RoPaSci::player_move_status(game_id, account)
+		}
+
+		fn payout_quote(game_id: Hash, winners: u32) -> Option<Balance> {
+			RoPaSci::payout_quote(game_id, winners)
+		}
+
+		fn next_deadlines() -> Vec<(BlockNumber, Hash)> {
+			RoPaSci::next_deadlines()
+		}
+
+		fn jackpot() -> Balance {
+			RoPaSci::jackpot()
+		}
+
+		fn game_updates() -> Vec<pallet_ropasci::GameUpdate<Hash, AccountId, BlockNumber, Balance, AssetId>> {
+			RoPaSci::game_updates()
+		}
+
+		fn game_result(game_id: Hash) -> Option<pallet_ropasci::GameResult<AccountId, BlockNumber, Balance>> {
+			RoPaSci::game_result(game_id)
+		}
+
+		fn player_stats(account: AccountId) -> pallet_ropasci::PlayerStatistics {
+			RoPaSci::player_stats(account)
+		}
+
+		fn rating(account: AccountId) -> pallet_ropasci::Rating {
+			RoPaSci::rating(account)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
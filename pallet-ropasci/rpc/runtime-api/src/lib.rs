@@ -0,0 +1,53 @@
+//! Runtime API definition for the RoPaSci pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_ropasci::{Game, GameResult, GameStage, GameUpdate, Hand, MoveStatus, PlayerStatistics, Rating};
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+    /// The API to query the games in progress.
+    pub trait RoPaSciApi<GameId, AccountId, BlockNumber, Balance, AssetId> where
+        GameId: Codec,
+        AccountId: Codec,
+        BlockNumber: Codec,
+        Balance: Codec,
+        AssetId: Codec,
+    {
+        /// The game with the given id, if it is in progress.
+        fn game(game_id: GameId) -> Option<Game<BlockNumber, Balance>>;
+
+        /// The games in "betting" stage which can still be joined, paginated by `offset` and
+        /// `limit`.
+        fn open_games(offset: u32, limit: u32) -> Vec<(GameId, Game<BlockNumber, Balance>)>;
+
+        /// The ids of the games in progress the account has made a move in.
+        fn games_of(account: AccountId) -> Vec<GameId>;
+
+        /// The status of the move of the account in the given game, if the account has made one.
+        fn player_move_status(game_id: GameId, account: AccountId) -> Option<MoveStatus>;
+
+        /// The reward every winner of the given game would receive if there were `winners` of them,
+        /// including the jackpot a sole winner takes.
+        fn payout_quote(game_id: GameId, winners: u32) -> Option<Balance>;
+
+        /// The upcoming stage deadlines of the games in progress ordered by block number.
+        fn next_deadlines() -> Vec<(BlockNumber, GameId)>;
+
+        /// The progressive jackpot the next sole winner of a large enough game receives.
+        fn jackpot() -> Balance;
+
+        /// The updates of the games made in the block.
+        fn game_updates() -> Vec<GameUpdate<GameId, AccountId, BlockNumber, Balance, AssetId>>;
+
+        /// The result of the recently ended game with the given id.
+        fn game_result(game_id: GameId) -> Option<GameResult<AccountId, BlockNumber, Balance>>;
+
+        /// The statistics of all the games played by the account.
+        fn player_stats(account: AccountId) -> PlayerStatistics;
+
+        /// The skill rating of the account based on the duels it played.
+        fn rating(account: AccountId) -> Rating;
+    }
+}
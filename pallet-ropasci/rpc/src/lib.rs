@@ -0,0 +1,232 @@
+//! RPC interface for the RoPaSci pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use futures::{future, stream, FutureExt, Stream, StreamExt};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorObject},
+		SubscriptionResult,
+	},
+	SubscriptionSink,
+};
+use pallet_ropasci_runtime_api::{Game, GameUpdate};
+use sc_client_api::BlockchainEvents;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::traits::SpawnNamed;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_ropasci_runtime_api::RoPaSciApi as RoPaSciRuntimeApi;
+
+/// RoPaSci RPC methods.
+#[rpc(client, server)]
+pub trait RoPaSciApi<BlockHash, GameId, AccountId, BlockNumber, Balance, AssetId> {
+	/// The game with the given id, if it is in progress.
+	#[method(name = "ropasci_game")]
+	fn game(
+		&self,
+		game_id: GameId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Game<BlockNumber, Balance>>>;
+
+	/// The games in "betting" stage which can still be joined, paginated by `offset` and `limit`.
+	#[method(name = "ropasci_openGames")]
+	fn open_games(
+		&self,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(GameId, Game<BlockNumber, Balance>)>>;
+
+	/// The ids of the games in progress the account has made a move in.
+	#[method(name = "ropasci_gamesOf")]
+	fn games_of(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<GameId>>;
+
+	/// The reward every winner of the given game would receive if there were `winners` of them,
+	/// including the jackpot a sole winner takes.
+	#[method(name = "ropasci_payoutQuote")]
+	fn payout_quote(
+		&self,
+		game_id: GameId,
+		winners: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Balance>>;
+
+	/// Streams the updates of the game with the given id as the new best blocks are imported.
+	#[subscription(
+		name = "ropasci_subscribeGame" => "ropasci_gameUpdate",
+		unsubscribe = "ropasci_unsubscribeGame",
+		item = GameUpdate<GameId, AccountId, BlockNumber, Balance, AssetId>,
+	)]
+	fn subscribe_game(&self, game_id: GameId);
+
+	/// Streams the updates of all the games as the new best blocks are imported.
+	#[subscription(
+		name = "ropasci_subscribeLobby" => "ropasci_lobbyUpdate",
+		unsubscribe = "ropasci_unsubscribeLobby",
+		item = GameUpdate<GameId, AccountId, BlockNumber, Balance, AssetId>,
+	)]
+	fn subscribe_lobby(&self);
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides RPC methods to query the RoPaSci games.
+pub struct RoPaSci<C, Block> {
+	client: Arc<C>,
+	executor: Arc<dyn SpawnNamed>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> RoPaSci<C, Block> {
+	/// Create new `RoPaSci` with the given reference to the client and the executor to drive the
+	/// subscriptions.
+	pub fn new(client: Arc<C>, executor: Arc<dyn SpawnNamed>) -> Self {
+		Self { client, executor, _marker: Default::default() }
+	}
+
+	fn spawn_subscription<S, T>(&self, mut sink: SubscriptionSink, stream: S)
+	where
+		S: Stream<Item = T> + Unpin + Send + 'static,
+		T: Serialize + Send + 'static,
+	{
+		let fut = async move {
+			sink.pipe_from_stream(stream).await;
+		};
+
+		self.executor.spawn("ropasci-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+impl<C, Block> RoPaSci<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	fn block_id(&self, at: Option<Block::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+/// The updates of the games made in every new best block.
+fn game_updates<C, Block, GameId, AccountId, BlockNumber, Balance, AssetId>(
+	client: Arc<C>,
+) -> impl Stream<Item = GameUpdate<GameId, AccountId, BlockNumber, Balance, AssetId>> + Unpin + Send
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+	C::Api: RoPaSciRuntimeApi<Block, GameId, AccountId, BlockNumber, Balance, AssetId>,
+	GameId: Codec + Send + 'static,
+	AccountId: Codec + Send + 'static,
+	BlockNumber: Codec + Send + 'static,
+	Balance: Codec + Send + 'static,
+	AssetId: Codec + Send + 'static,
+{
+	client
+		.import_notification_stream()
+		.filter(|notification| future::ready(notification.is_new_best))
+		.flat_map(move |notification| {
+			let updates = client
+				.runtime_api()
+				.game_updates(&BlockId::hash(notification.hash))
+				.unwrap_or_default();
+			stream::iter(updates)
+		})
+}
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query RoPaSci state.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+#[async_trait]
+impl<C, Block, GameId, AccountId, BlockNumber, Balance, AssetId>
+	RoPaSciApiServer<<Block as BlockT>::Hash, GameId, AccountId, BlockNumber, Balance, AssetId>
+	for RoPaSci<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C: Send + Sync + 'static,
+	C::Api: RoPaSciRuntimeApi<Block, GameId, AccountId, BlockNumber, Balance, AssetId>,
+	GameId: Codec + PartialEq + Serialize + DeserializeOwned + Send + Sync + 'static,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	AssetId: Codec + Serialize + Send + Sync + 'static,
+{
+	fn game(
+		&self,
+		game_id: GameId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Game<BlockNumber, Balance>>> {
+		self.client
+			.runtime_api()
+			.game(&self.block_id(at), game_id)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn open_games(
+		&self,
+		offset: u32,
+		limit: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(GameId, Game<BlockNumber, Balance>)>> {
+		self.client
+			.runtime_api()
+			.open_games(&self.block_id(at), offset, limit)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn games_of(&self, account: AccountId, at: Option<Block::Hash>) -> RpcResult<Vec<GameId>> {
+		self.client
+			.runtime_api()
+			.games_of(&self.block_id(at), account)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn payout_quote(
+		&self,
+		game_id: GameId,
+		winners: u32,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Balance>> {
+		self.client
+			.runtime_api()
+			.payout_quote(&self.block_id(at), game_id, winners)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn subscribe_game(&self, sink: SubscriptionSink, game_id: GameId) -> SubscriptionResult {
+		let updates = game_updates(self.client.clone())
+			.filter(move |update| future::ready(*update.game_id() == game_id));
+
+		self.spawn_subscription(sink, updates);
+		Ok(())
+	}
+
+	fn subscribe_lobby(&self, sink: SubscriptionSink) -> SubscriptionResult {
+		self.spawn_subscription(sink, game_updates(self.client.clone()));
+		Ok(())
+	}
+}
@@ -0,0 +1,166 @@
+use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+/// A change of a game which happened in a block.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum GameUpdate<GameId, AccountId, BlockNumber, Balance, AssetId> {
+    GameStarted {
+        game_id: GameId,
+        owner: AccountId,
+        round_length: BlockNumber,
+        bet: Balance,
+        asset: Option<AssetId>,
+        deposit: Balance,
+        schedule: Option<ScheduleId>,
+    },
+    BetPlaced { game_id: GameId, player: AccountId },
+    RevealingStarted { game_id: GameId, deadline: BlockNumber },
+    MoveRevealed { game_id: GameId, player: AccountId },
+    Refunded { game_id: GameId, players: Vec<AccountId>, amount: Balance },
+    GameEnded {
+        game_id: GameId,
+        winners: Vec<AccountId>,
+        reward: Balance,
+        players: Vec<(AccountId, Option<Hand>, Balance)>,
+    },
+}
+
+impl<GameId, AccountId, BlockNumber, Balance, AssetId> GameUpdate<GameId, AccountId, BlockNumber, Balance, AssetId> {
+    pub fn game_id(&self) -> &GameId {
+        match self {
+            GameUpdate::GameStarted { game_id, .. } |
+            GameUpdate::BetPlaced { game_id, .. } |
+            GameUpdate::RevealingStarted { game_id, .. } |
+            GameUpdate::MoveRevealed { game_id, .. } |
+            GameUpdate::Refunded { game_id, .. } |
+            GameUpdate::GameEnded { game_id, .. } => game_id,
+        }
+    }
+}
+
+type GameUpdateOf<T> = GameUpdate<
+    GameId<T>,
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+    AssetIdOf<T>,
+>;
+
+impl<T: Config> Pallet<T> {
+    /// The game with the given id, if it is in progress.
+    pub fn game(game_id: GameId<T>) -> Option<GameOf<T>> {
+        Games::<T>::get(game_id)
+    }
+
+    /// The games in "betting" stage which can still be joined, paginated by `offset` and `limit`.
+    pub fn open_games(offset: u32, limit: u32) -> Vec<(GameId<T>, GameOf<T>)> {
+        Games::<T>::iter()
+            .filter(|(_, game)| Self::can_join_game(game))
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// The ids of the games in progress the account has made a move in.
+    pub fn games_of(account: T::AccountId) -> Vec<GameId<T>> {
+        Moves::<T>::iter_keys()
+            .filter(|(_, player)| *player == account)
+            .map(|(game_id, _)| game_id)
+            .collect()
+    }
+
+    /// The status of the move of the account in the given game, if the account has made one.
+    pub fn player_move_status(game_id: GameId<T>, account: T::AccountId) -> Option<MoveStatus> {
+        Moves::<T>::get(game_id, account).as_ref().map(MoveStatus::from)
+    }
+
+    /// The reward every winner of the given game would receive if there were `winners` of them,
+    /// computed like the game settles: the owner of a prize game does not bet and a sole winner of
+    /// a large enough native currency game also wins the jackpot. The prize item is not included.
+    pub fn payout_quote(game_id: GameId<T>, winners: u32) -> Option<BalanceOf<T>> {
+        let game = Games::<T>::get(game_id)?;
+        let players = Moves::<T>::iter_prefix(game_id).count();
+        let has_prize = GamePrizes::<T>::contains_key(game_id);
+        let bettors = players.saturating_sub(has_prize as usize);
+        let money_pool = game.bet.saturating_mul(BalanceOf::<T>::saturated_from(bettors));
+        let rake = PalletConfig::<T>::get().rake * money_pool;
+        let counts_profit = !GameAssets::<T>::contains_key(game_id) && !has_prize;
+        let jackpot_cut = if counts_profit { T::JackpotShare::get() * money_pool } else { Zero::zero() };
+        let reward = money_pool.saturating_sub(rake).saturating_sub(jackpot_cut)
+            .checked_div(&BalanceOf::<T>::from(winners))?;
+
+        // the jackpot the sole winner takes already includes the cut of this game
+        let wins_jackpot = counts_profit && winners == 1 && players >= T::JackpotMinPlayers::get() as usize;
+        Some(if wins_jackpot {
+            reward.saturating_add(Jackpot::<T>::get()).saturating_add(jackpot_cut)
+        } else {
+            reward
+        })
+    }
+
+    /// The upcoming stage deadlines of the games in progress ordered by block number.
+    pub fn next_deadlines() -> Vec<(T::BlockNumber, GameId<T>)> {
+        let mut deadlines = BettingGamesIndex::<T>::iter()
+            .chain(RevealingGamesIndex::<T>::iter())
+            .flat_map(|(block, game_ids)| game_ids.into_iter().map(move |game_id| (block, game_id)))
+            .filter(|(_, game_id)| Games::<T>::contains_key(game_id))
+            .collect::<Vec<_>>();
+
+        deadlines.sort();
+        deadlines
+    }
+
+    /// The result of the recently ended game with the given id.
+    pub fn game_result(game_id: GameId<T>) -> Option<GameResultOf<T>> {
+        GameResults::<T>::get(game_id)
+    }
+
+    /// The statistics of all the games played by the account.
+    pub fn player_stats(account: T::AccountId) -> PlayerStatistics {
+        PlayerStats::<T>::get(account)
+    }
+
+    /// The skill rating of the account based on the duels it played.
+    pub fn rating(account: T::AccountId) -> Rating {
+        Ratings::<T>::get(account)
+    }
+
+    /// The progressive jackpot the next sole winner of a large enough game receives.
+    pub fn jackpot() -> BalanceOf<T> {
+        Jackpot::<T>::get()
+    }
+
+    /// The updates of the games made in the current block.
+    pub fn game_updates() -> Vec<GameUpdateOf<T>>
+        where <T as frame_system::Config>::Event: TryInto<Event<T>>
+    {
+        frame_system::Pallet::<T>::events()
+            .into_iter()
+            .filter_map(|record| record.event.clone().try_into().ok())
+            .filter_map(Self::game_update)
+            .collect()
+    }
+
+    fn game_update(event: Event<T>) -> Option<GameUpdateOf<T>> {
+        match event {
+            Event::GameStarted { game_id, owner, round_length, bet, asset, deposit, schedule } =>
+                Some(GameUpdate::GameStarted { game_id, owner, round_length, bet, asset, deposit, schedule }),
+            Event::BetPlaced { game_id, player } =>
+                Some(GameUpdate::BetPlaced { game_id, player }),
+            Event::RevealingStarted { game_id, deadline } =>
+                Some(GameUpdate::RevealingStarted { game_id, deadline }),
+            Event::MoveRevealed { game_id, player } =>
+                Some(GameUpdate::MoveRevealed { game_id, player }),
+            Event::Refunded { game_id, players, amount } |
+            Event::ForceRefunded { game_id, players, amount } =>
+                Some(GameUpdate::Refunded { game_id, players, amount }),
+            Event::GameEnded { game_id, winners, reward, players } =>
+                Some(GameUpdate::GameEnded { game_id, winners, reward, players }),
+            _ => None,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::tokens::{fungibles, nonfungibles}};
 use frame_system::RawOrigin;
-use sp_runtime::traits::Bounded;
+use sp_runtime::traits::{Bounded, TrailingZeroInput};
 
 use crate::game::Hand;
 use crate::Pallet as RoPaSci;
@@ -17,7 +17,12 @@ fn get_player<T: Config>(index: u32) -> T::AccountId {
 }
 
 fn start_new_game<T: Config>(seed: &[u8]) -> GameId<T> {
-    let owner = get_player::<T>(1);
+    start_new_game_of::<T>(1, seed)
+}
+
+// Every owner may only play `MaxActiveGamesPerAccount` games at once.
+fn start_new_game_of<T: Config>(owner_index: u32, seed: &[u8]) -> GameId<T> {
+    let owner = get_player::<T>(owner_index);
     let round_length = 20u32.into();
     let bet = 10u32.into();
     let move_hash = T::MoveHasher::hash(seed);
@@ -26,7 +31,124 @@ fn start_new_game<T: Config>(seed: &[u8]) -> GameId<T> {
     move_hash
 }
 
+// Starts a game of `p` players, the caller included, which the owner wins with paper and reveals
+// all the moves but the one of the caller.
+fn start_revealing_game<T: Config>(caller: &T::AccountId, move_hash: T::MoveHash, p: u32) -> GameId<T> {
+    PalletConfig::<T>::mutate(|config| config.max_players = MaxPlayers::get());
+    let owner_reveal = vec![Hand::Paper as u8];
+    let game_id = start_new_game::<T>(&owner_reveal);
+    assert_ok!(RoPaSci::<T>::join(RawOrigin::Signed(caller.clone()).into(), game_id, move_hash));
+    let others = (3..=p)
+        .map(|index| {
+            let player = get_player::<T>(index);
+            let move_reveal = (Hand::Rock as u8, index).encode();
+            let move_hash = T::MoveHasher::hash(&move_reveal);
+            assert_ok!(RoPaSci::<T>::join(RawOrigin::Signed(player.clone()).into(), game_id, move_hash));
+            (player, move_reveal)
+        })
+        .collect::<Vec<_>>();
+
+    // end betting
+    frame_system::Pallet::<T>::set_block_number(20u32.into());
+    RoPaSci::<T>::on_initialize(20u32.into());
+    let owner = account("player", 1, SEED);
+    assert_ok!(RoPaSci::<T>::reveal(RawOrigin::Signed(owner).into(), game_id, owner_reveal));
+    for (player, move_reveal) in others {
+        assert_ok!(RoPaSci::<T>::reveal(RawOrigin::Signed(player).into(), game_id, move_reveal));
+    }
+    game_id
+}
+
+fn play_new_house_game<T: Config>(player_index: u32, seed: &[u8]) -> GameId<T> {
+    let player = get_player::<T>(player_index);
+    let bet = 10u32.into();
+    let move_hash = T::MoveHasher::hash(seed);
+
+    assert_ok!(RoPaSci::<T>::play_house(RawOrigin::Signed(player).into(), bet, move_hash));
+    move_hash
+}
+
+fn post_new_challenge<T: Config>(challenger_index: u32, seed: &[u8]) -> GameId<T> {
+    let challenger = get_player::<T>(challenger_index);
+    let stake = 10u32.into();
+    let move_hash = T::MoveHasher::hash(seed);
+
+    assert_ok!(RoPaSci::<T>::post_challenge(RawOrigin::Signed(challenger).into(), stake, move_hash));
+    move_hash
+}
+
+// Records the result of a game the two players may rematch.
+fn record_new_result<T: Config>(first_index: u32, seed: &[u8]) -> GameId<T> {
+    let game_id = T::MoveHasher::hash(seed);
+    let players = (first_index..first_index + 2)
+        .map(|index| (get_player::<T>(index), Some(Hand::Rock), Zero::zero()))
+        .collect::<Vec<_>>();
+    let round_length = 20u32.into();
+    // a game ends a round after it starts at the earliest
+    if frame_system::Pallet::<T>::block_number() < round_length {
+        frame_system::Pallet::<T>::set_block_number(round_length);
+    }
+
+    GameResults::<T>::insert(game_id, GameResult {
+        ended_at: frame_system::Pallet::<T>::block_number(),
+        round_length,
+        bet: 10u32.into(),
+        winners: Default::default(),
+        players: players.try_into().unwrap(),
+        rematchable: true,
+    });
+    game_id
+}
+
+// Creates a sufficient asset held by the given accounts and the pallet account.
+fn create_asset<T: Config>(holders: &[T::AccountId]) -> AssetIdOf<T>
+where
+    T::Assets: fungibles::Create<T::AccountId> + fungibles::Mutate<T::AccountId>,
+    AssetIdOf<T>: From<u32>,
+{
+    // the id of an asset which does not exist yet
+    let asset = 1_000u32.into();
+    let admin = account("admin", 0, SEED);
+    assert_ok!(<T::Assets as fungibles::Create<_>>::create(asset, admin, true, 1u32.into()));
+    for holder in holders.iter().chain(sp_std::iter::once(&RoPaSci::<T>::account_id())) {
+        assert_ok!(<T::Assets as fungibles::Mutate<_>>::mint_into(asset, holder, 1_000u32.into()));
+    }
+    asset
+}
+
+// Creates a prize item owned by the given account.
+fn mint_prize<T: Config>(owner: &T::AccountId) -> (CollectionIdOf<T>, ItemIdOf<T>)
+where
+    T::Items: nonfungibles::Create<T::AccountId> + nonfungibles::Mutate<T::AccountId>,
+    CollectionIdOf<T>: From<u32>,
+    ItemIdOf<T>: From<u32>,
+{
+    // the id of a collection which does not exist yet
+    let (collection, item) = (1_000u32.into(), 0u32.into());
+    assert_ok!(<T::Items as nonfungibles::Create<_>>::create_collection(&collection, owner, owner));
+    assert_ok!(<T::Items as nonfungibles::Mutate<_>>::mint_into(&collection, &item, owner));
+    (collection, item)
+}
+
+fn fund_bankroll<T: Config>() {
+    let bankroll = BalanceOf::<T>::max_value() / 2u32.into();
+    T::Currency::make_free_balance_be(&RoPaSci::<T>::house_account(), bankroll);
+}
+
+fn house_draw_block<T: Config>() -> T::BlockNumber {
+    frame_system::Pallet::<T>::block_number() + T::HouseDrawDelay::get().max(1).into()
+}
+
 benchmarks! {
+    where_clause {
+        where
+            T::Assets: fungibles::Create<T::AccountId> + fungibles::Mutate<T::AccountId>,
+            AssetIdOf<T>: From<u32>,
+            T::Items: nonfungibles::Create<T::AccountId> + nonfungibles::Mutate<T::AccountId>,
+            CollectionIdOf<T>: From<u32>,
+            ItemIdOf<T>: From<u32>,
+    }
+
     start_game {
         let caller = get_player::<T>(1);
 		let round_length = 20u32.into();
@@ -46,35 +168,228 @@ benchmarks! {
         assert!(Moves::<T>::contains_key(game_id, caller));
     }
 
+    start_game_with_asset {
+        let caller = get_player::<T>(1);
+        let asset = create_asset::<T>(&[caller.clone()]);
+        let move_hash = T::MoveHasher::hash(b"move");
+    }: start_with_asset(RawOrigin::Signed(caller), 20u32.into(), 10u32.into(), asset, move_hash)
+    verify {
+        assert_eq!(Some(asset), GameAssets::<T>::get(move_hash));
+    }
+
+    start_game_with_prize {
+        let caller = get_player::<T>(1);
+        let (collection, item) = mint_prize::<T>(&caller);
+        let move_hash = T::MoveHasher::hash(b"move");
+    }: start_with_prize(RawOrigin::Signed(caller), 20u32.into(), 10u32.into(), collection, item, move_hash)
+    verify {
+        assert!(GamePrizes::<T>::contains_key(move_hash));
+    }
+
+    join_game_with_asset {
+        let owner = get_player::<T>(1);
+        let caller = get_player::<T>(2);
+        let asset = create_asset::<T>(&[owner.clone(), caller.clone()]);
+        let game_id = T::MoveHasher::hash(b"game");
+        assert_ok!(RoPaSci::<T>::start_with_asset(
+            RawOrigin::Signed(owner).into(),
+            20u32.into(),
+            10u32.into(),
+            asset,
+            game_id));
+        let move_hash = T::MoveHasher::hash(b"move");
+    }: join(RawOrigin::Signed(caller.clone()), game_id, move_hash)
+    verify {
+        assert!(Moves::<T>::contains_key(game_id, caller));
+    }
+
+    claim_payout {
+        let caller = get_player::<T>(1);
+        let asset = create_asset::<T>(&[]);
+        UnclaimedPayouts::<T>::insert(&caller, Some(asset), BalanceOf::<T>::from(10u32));
+    }: _(RawOrigin::Signed(caller.clone()), Some(asset))
+    verify {
+        assert!(!UnclaimedPayouts::<T>::contains_key(&caller, Some(asset)));
+    }
+
+    withdraw_asset_rake {
+        let origin = T::AdminOrigin::successful_origin();
+        let asset = create_asset::<T>(&[]);
+        AssetRakes::<T>::insert(asset, BalanceOf::<T>::from(10u32));
+        let dest = get_player::<T>(1);
+    }: _<T::Origin>(origin, asset, dest)
+    verify {
+        assert!(!AssetRakes::<T>::contains_key(asset));
+    }
+
+    claim_prize {
+        let caller = get_player::<T>(1);
+        let (collection, item) = mint_prize::<T>(&caller);
+        assert_ok!(<T::Items as nonfungibles::Transfer<_>>::transfer(&collection, &item, &RoPaSci::<T>::account_id()));
+        let game_id = T::MoveHasher::hash(b"game");
+        GamePrizes::<T>::insert(game_id, (caller.clone(), collection, item));
+    }: _(RawOrigin::Signed(caller.clone()), game_id)
+    verify {
+        assert!(!GamePrizes::<T>::contains_key(game_id));
+    }
+
+    start_team_game {
+        let caller = get_player::<T>(1);
+        let round_length = 20u32.into();
+        let bet = 10u32.into();
+        let move_hash = T::MoveHasher::hash(b"move");
+    }: start_team(RawOrigin::Signed(caller), round_length, bet, 2, move_hash)
+    verify {
+        assert!(TeamGames::<T>::contains_key(move_hash));
+    }
+
+    join_team {
+        let owner = get_player::<T>(1);
+        let game_id = T::MoveHasher::hash(b"game");
+        assert_ok!(RoPaSci::<T>::start_team(RawOrigin::Signed(owner).into(), 20u32.into(), 10u32.into(), 2, game_id));
+        let caller = get_player::<T>(2);
+        let move_hash = T::MoveHasher::hash(b"move");
+    }: _(RawOrigin::Signed(caller.clone()), game_id, Team::Second, move_hash)
+    verify {
+        assert_eq!(Some(Team::Second), TeamMembers::<T>::get(game_id, caller));
+    }
+
+    side_bet {
+        let game_id = start_new_game::<T>(b"game");
+        let player = get_player::<T>(1);
+        let caller = get_player::<T>(2);
+        let stake = 10u32.into();
+    }: _(RawOrigin::Signed(caller.clone()), game_id, player, stake)
+    verify {
+        assert!(SideBets::<T>::contains_key(game_id, caller));
+    }
+
+    settle_side_bets {
+        let s in 1 .. T::MaxSideBets::get();
+
+        let game_id = start_new_game::<T>(b"game");
+        let player = account("player", 1, SEED);
+        for i in 0..s {
+            // the spectators must be able to receive the payouts
+            let spectator = account("spectator", i, SEED);
+            T::Currency::make_free_balance_be(&spectator, T::Currency::minimum_balance() * 100u32.into());
+            assert_ok!(RoPaSci::<T>::side_bet(RawOrigin::Signed(spectator).into(), game_id, player.clone(), 10u32.into()));
+        }
+    }: {
+        RoPaSci::<T>::settle_side_bets(&game_id, &[player]);
+    }
+    verify {
+        assert!(!SideBetCounts::<T>::contains_key(game_id));
+    }
+
+    // the last reveal ends the game which the owner wins
     reveal_move {
         let r in 1 .. 10_000_000;
+        let p in 2 .. MaxPlayers::get();
 
-        let game_id = start_new_game::<T>(b"game");
         let caller = get_player::<T>(2);
         let move_reveal = vec![0u8; r as usize];
-        let move_hash = <T as Config>::MoveHasher::hash(&move_reveal);
-        assert_ok!(RoPaSci::<T>::join(RawOrigin::Signed(caller.clone()).into(), game_id, move_hash));
-
-        // end betting
-        frame_system::Pallet::<T>::set_block_number(20u32.into());
-        RoPaSci::<T>::on_initialize(20u32.into());
+        let game_id = start_revealing_game::<T>(&caller, T::MoveHasher::hash(&move_reveal), p);
     }: reveal(RawOrigin::Signed(caller.clone()), game_id, move_reveal)
     verify {
         assert!(matches!(
-            Moves::<T>::get(game_id, caller),
-            Some(Move {
-                hash,
-                hand: Some(Hand::Rock),
-            }) if hash == move_hash
+            GameResults::<T>::get(game_id),
+            Some(GameResult { winners, .. }) if winners.len() == 1
         ))
     }
 
+    reveal_unsigned {
+        let r in 1 .. 10_000_000;
+        let p in 2 .. MaxPlayers::get();
+
+        let caller = get_player::<T>(2);
+        let move_reveal = vec![0u8; r as usize];
+        let game_id = start_revealing_game::<T>(&caller, T::MoveHasher::hash(&move_reveal), p);
+
+        let payload = RevealPayload { player: caller.clone(), game_id, move_reveal, nonce: 0 };
+        // the signature of the `reveal_signing_payload` is only checked by the transaction
+        // validation, the call itself only checks the nonce
+        let signature = T::Signature::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+    }: _(RawOrigin::None, payload, signature)
+    verify {
+        assert_eq!(1, RevealNonces::<T>::get(&caller));
+    }
+
+    set_config {
+        let origin = T::AdminOrigin::successful_origin();
+        let config = GameConfig { max_players: 10, ..Default::default() };
+    }: _<T::Origin>(origin, config.clone())
+    verify {
+        assert_eq!(config, PalletConfig::<T>::get());
+    }
+
+    pause {
+        let origin = T::AdminOrigin::successful_origin();
+    }: _<T::Origin>(origin)
+    verify {
+        assert!(Paused::<T>::get());
+    }
+
+    unpause {
+        let origin = T::AdminOrigin::successful_origin();
+        Paused::<T>::put(true);
+    }: _<T::Origin>(origin)
+    verify {
+        assert!(!Paused::<T>::get());
+    }
+
+    force_end {
+        let p in 1 .. MaxPlayers::get();
+
+        PalletConfig::<T>::mutate(|config| config.max_players = MaxPlayers::get());
+        let origin = T::AdminOrigin::successful_origin();
+        let game_id = start_new_game::<T>(b"game");
+        for i in 2..=p {
+            let player = get_player::<T>(i);
+            assert_ok!(RoPaSci::<T>::join(RawOrigin::Signed(player).into(), game_id, T::MoveHasher::hash(b"move")));
+        }
+    }: _<T::Origin>(origin, game_id)
+    verify {
+        assert!(!Games::<T>::contains_key(game_id));
+    }
+
+    force_refund {
+        let p in 1 .. MaxPlayers::get();
+
+        PalletConfig::<T>::mutate(|config| config.max_players = MaxPlayers::get());
+        let origin = T::AdminOrigin::successful_origin();
+        let game_id = start_new_game::<T>(b"game");
+        for i in 2..=p {
+            let player = get_player::<T>(i);
+            assert_ok!(RoPaSci::<T>::join(RawOrigin::Signed(player).into(), game_id, T::MoveHasher::hash(b"move")));
+        }
+    }: _<T::Origin>(origin, game_id)
+    verify {
+        assert!(!Games::<T>::contains_key(game_id));
+    }
+
+    self_exclude {
+        let caller = get_player::<T>(1);
+        let until = T::BlockNumber::from(1000u32);
+    }: _(RawOrigin::Signed(caller.clone()), until)
+    verify {
+        assert_eq!(Some(until), SelfExclusions::<T>::get(caller));
+    }
+
+    set_spending_cap {
+        let caller = get_player::<T>(1);
+        let cap = BalanceOf::<T>::max_value();
+    }: _(RawOrigin::Signed(caller.clone()), Some(cap))
+    verify {
+        assert_eq!(Some(cap), SpendingCaps::<T>::get(caller));
+    }
+
     on_initialize_betting {
         let g in 1 .. 10_000;
 
         for i in 1..=g {
             let seed = vec![1u8; i as usize];
-            let _game_id = start_new_game::<T>(&seed);
+            let _game_id = start_new_game_of::<T>(i, &seed);
         }
 
         let block_number = 20u32.into();
@@ -89,14 +404,23 @@ benchmarks! {
     on_initialize_revealing {
         let g in 1 .. 10_000;
 
+        // every game is a duel which the owner wins as the joiner does not reveal
+        let joiner_hash = T::MoveHasher::hash(&[Hand::Rock as u8]);
         for i in 1..=g {
-            let seed = vec![1u8; i as usize];
-            let _game_id = start_new_game::<T>(&seed);
+            let seed = vec![Hand::Paper as u8; i as usize];
+            let game_id = start_new_game_of::<T>(i, &seed);
+            let joiner = get_player::<T>(g + i);
+            assert_ok!(RoPaSci::<T>::join(RawOrigin::Signed(joiner).into(), game_id, joiner_hash));
         }
 
         // end betting
         frame_system::Pallet::<T>::set_block_number(20u32.into());
         RoPaSci::<T>::on_initialize(20u32.into());
+        for i in 1..=g {
+            let seed = vec![Hand::Paper as u8; i as usize];
+            let owner = account("player", i, SEED);
+            assert_ok!(RoPaSci::<T>::reveal(RawOrigin::Signed(owner).into(), T::MoveHasher::hash(&seed), seed));
+        }
 
         let block_number = 40u32.into();
         frame_system::Pallet::<T>::set_block_number(block_number);
@@ -105,6 +429,225 @@ benchmarks! {
     }
     verify {
         assert!(!RevealingGamesIndex::<T>::contains_key(block_number));
+        assert_eq!(g as usize, GameResults::<T>::iter().count());
+    }
+
+    set_tie_break {
+        let caller = get_player::<T>(1);
+        let game_id = start_new_game::<T>(b"game");
+    }: _(RawOrigin::Signed(caller), game_id, TieBreak::Random)
+    verify {
+        assert_eq!(Some(TieBreak::Random), GameTieBreaks::<T>::get(game_id));
+    }
+
+    set_jackpot_rollover {
+        let caller = get_player::<T>(1);
+        let game_id = start_new_game::<T>(b"game");
+    }: _(RawOrigin::Signed(caller), game_id, true)
+    verify {
+        assert!(JackpotRollovers::<T>::contains_key(game_id));
+    }
+
+    play_house {
+        fund_bankroll::<T>();
+        let caller = get_player::<T>(1);
+        let bet = 10u32.into();
+        let move_hash = T::MoveHasher::hash(b"move");
+    }: _(RawOrigin::Signed(caller), bet, move_hash)
+    verify {
+        assert!(HouseGames::<T>::contains_key(move_hash));
+    }
+
+    reveal_house {
+        fund_bankroll::<T>();
+        let caller = get_player::<T>(1);
+        let move_reveal = vec![Hand::Rock as u8];
+        let game_id = play_new_house_game::<T>(1, &move_reveal);
+
+        let block_number = house_draw_block::<T>();
+        frame_system::Pallet::<T>::set_block_number(block_number);
+        RoPaSci::<T>::on_initialize(block_number);
+    }: _(RawOrigin::Signed(caller), game_id, move_reveal)
+    verify {
+        assert!(!HouseGames::<T>::contains_key(game_id));
+    }
+
+    on_initialize_house {
+        let g in 1 .. 10_000;
+
+        fund_bankroll::<T>();
+        let block_number = house_draw_block::<T>();
+        for i in 1..=g {
+            let seed = vec![1u8; i as usize];
+            let _game_id = play_new_house_game::<T>(i, &seed);
+        }
+
+        frame_system::Pallet::<T>::set_block_number(block_number);
+    }: {
+        RoPaSci::<T>::on_initialize(block_number);
+    }
+    verify {
+        assert!(!HouseGamesIndex::<T>::contains_key(block_number));
+    }
+
+    fund_house {
+        let caller = get_player::<T>(1);
+        let amount = 1_000u32.into();
+    }: _(RawOrigin::Signed(caller), amount)
+    verify {
+        assert!(T::Currency::free_balance(&RoPaSci::<T>::house_account()) >= amount);
+    }
+
+    withdraw_house {
+        let origin = T::AdminOrigin::successful_origin();
+        fund_bankroll::<T>();
+        let dest = account("dest", 0, SEED);
+        let amount = 1_000u32.into();
+    }: _<T::Origin>(origin, dest.clone(), amount)
+    verify {
+        assert_eq!(amount, T::Currency::free_balance(&dest));
+    }
+
+    claim_house_payout {
+        fund_bankroll::<T>();
+        let caller: T::AccountId = account("player", 1, SEED);
+        let amount = 1_000u32.into();
+        HouseDebts::<T>::insert(&caller, amount);
+        HouseLiability::<T>::put(amount);
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!HouseDebts::<T>::contains_key(&caller));
+    }
+
+    post_challenge {
+        let caller = get_player::<T>(1);
+        let stake = 10u32.into();
+        let move_hash = T::MoveHasher::hash(b"move");
+    }: _(RawOrigin::Signed(caller), stake, move_hash)
+    verify {
+        assert!(Challenges::<T>::contains_key(move_hash));
+    }
+
+    cancel_challenge {
+        let caller = get_player::<T>(1);
+        let game_id = post_new_challenge::<T>(1, &[Hand::Rock as u8]);
+    }: _(RawOrigin::Signed(caller), game_id)
+    verify {
+        assert!(Challenges::<T>::get(game_id).unwrap().cancel_at.is_some());
+    }
+
+    respond_challenge {
+        let game_id = post_new_challenge::<T>(1, &[Hand::Rock as u8]);
+        // the worst case answers a challenge whose cancellation is requested
+        assert_ok!(RoPaSci::<T>::cancel_challenge(RawOrigin::Signed(get_player::<T>(1)).into(), game_id));
+        let caller = get_player::<T>(2);
+    }: _(RawOrigin::Signed(caller), game_id, Hand::Paper)
+    verify {
+        assert!(Challenges::<T>::get(game_id).unwrap().response.is_some());
+    }
+
+    reveal_challenge {
+        let move_reveal = vec![Hand::Rock as u8];
+        let game_id = post_new_challenge::<T>(1, &move_reveal);
+        assert_ok!(RoPaSci::<T>::respond_challenge(RawOrigin::Signed(get_player::<T>(2)).into(), game_id, Hand::Paper));
+        let caller = get_player::<T>(1);
+    }: _(RawOrigin::Signed(caller), game_id, move_reveal)
+    verify {
+        assert!(!Challenges::<T>::contains_key(game_id));
+    }
+
+    on_initialize_challenges {
+        let g in 1 .. 10_000;
+
+        let responder_index = g + 1;
+        for i in 1..=g {
+            let seed = vec![1u8; i as usize];
+            let game_id = post_new_challenge::<T>(i, &seed);
+            // every responder may only play `MaxActiveGamesPerAccount` games at once
+            let responder = get_player::<T>(responder_index + i);
+            assert_ok!(RoPaSci::<T>::respond_challenge(RawOrigin::Signed(responder).into(), game_id, Hand::Paper));
+        }
+
+        let block_number = frame_system::Pallet::<T>::block_number() + T::ChallengeRevealLength::get().max(1).into();
+        frame_system::Pallet::<T>::set_block_number(block_number);
+    }: {
+        RoPaSci::<T>::on_initialize(block_number);
+    }
+    verify {
+        assert!(!ChallengesIndex::<T>::contains_key(block_number));
+    }
+
+    schedule_game {
+        let caller = get_player::<T>(1);
+        let round_length = 20u32.into();
+        let bet = 10u32.into();
+        let opens_at = 100u32.into();
+    }: _(RawOrigin::Signed(caller), round_length, bet, opens_at, Some(100u32.into()), T::MaxScheduleRepeats::get())
+    verify {
+        assert!(Schedules::<T>::contains_key(0));
+    }
+
+    cancel_schedule {
+        let caller = get_player::<T>(1);
+        assert_ok!(RoPaSci::<T>::schedule_game(
+            RawOrigin::Signed(caller.clone()).into(), 20u32.into(), 10u32.into(), 100u32.into(), None, 0));
+    }: _(RawOrigin::Signed(caller), 0)
+    verify {
+        assert!(!Schedules::<T>::contains_key(0));
+    }
+
+    on_initialize_schedules {
+        let g in 1 .. T::MaxSchedulesPerBlock::get();
+
+        let caller = get_player::<T>(1);
+        let block_number = 100u32.into();
+        for _ in 0..g {
+            assert_ok!(RoPaSci::<T>::schedule_game(
+                RawOrigin::Signed(caller.clone()).into(),
+                20u32.into(),
+                10u32.into(),
+                block_number,
+                Some(100u32.into()),
+                1));
+        }
+
+        frame_system::Pallet::<T>::set_block_number(block_number);
+    }: {
+        RoPaSci::<T>::on_initialize(block_number);
+    }
+    verify {
+        assert!(!SchedulesIndex::<T>::contains_key(block_number));
+    }
+
+    rematch {
+        let game_id = record_new_result::<T>(1, b"game");
+        let player = get_player::<T>(1);
+        assert_ok!(RoPaSci::<T>::rematch(RawOrigin::Signed(player).into(), game_id, T::MoveHasher::hash(b"first")));
+        let caller = get_player::<T>(2);
+        let move_hash = T::MoveHasher::hash(b"second");
+    }: _(RawOrigin::Signed(caller), game_id, move_hash)
+    verify {
+        assert!(RematchGames::<T>::iter_values().any(|previous| previous == game_id));
+    }
+
+    on_initialize_rematches {
+        let g in 1 .. 10_000;
+
+        for i in 1..=g {
+            let seed = vec![1u8; i as usize];
+            // every player may only play `MaxActiveGamesPerAccount` games at once
+            let game_id = record_new_result::<T>(2 * i, &seed);
+            let player = get_player::<T>(2 * i);
+            assert_ok!(RoPaSci::<T>::rematch(RawOrigin::Signed(player).into(), game_id, T::MoveHasher::hash(&seed)));
+        }
+
+        let block_number = frame_system::Pallet::<T>::block_number() + T::RematchWindow::get().max(1).into();
+        frame_system::Pallet::<T>::set_block_number(block_number);
+    }: {
+        RoPaSci::<T>::on_initialize(block_number);
+    }
+    verify {
+        assert!(!RematchesIndex::<T>::contains_key(block_number));
     }
 }
 
@@ -1,12 +1,18 @@
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::ConstU32};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{PerThing, Permill, traits::Bounded};
+use sp_std::prelude::*;
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum GameStage {
     Betting { participating_players: u64 },
     Revealing { anticipated_players: u64 },
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Game<BlockNumber, Balance> {
     pub start: BlockNumber,
     pub round_length: BlockNumber,
@@ -24,6 +30,16 @@ impl<BlockNumber, Balance> Game<BlockNumber, Balance> {
         }
     }
 
+    /// A game without players which anyone can join, like the instances of the schedules.
+    pub fn open(start: BlockNumber, round_length: BlockNumber, bet: Balance) -> Self {
+        Self {
+            start,
+            round_length,
+            bet,
+            stage: GameStage::Betting { participating_players: 0 },
+        }
+    }
+
     pub fn join(&mut self) {
         match self.stage {
             GameStage::Betting { participating_players } =>
@@ -54,6 +70,7 @@ impl<BlockNumber, Balance> Game<BlockNumber, Balance> {
 }
 
 #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Hand {
     Rock,
     Paper,
@@ -77,6 +94,34 @@ impl Hand {
             Hand::Scissors => Hand::Rock,
         }
     }
+
+    /// The most common of the hands. Of two equally common hands the one beating the other is the
+    /// majority, there is none if all the three hands are equally common.
+    pub fn majority(hands: &[Hand]) -> Option<Self> {
+        let mut counts = [0usize; 3];
+        for hand in hands {
+            counts[*hand as usize] += 1;
+        }
+
+        let max_count = counts.iter().copied().max().filter(|count| *count > 0)?;
+        let common = [Hand::Rock, Hand::Paper, Hand::Scissors].into_iter()
+            .filter(|hand| counts[*hand as usize] == max_count)
+            .collect::<Vec<_>>();
+        match common[..] {
+            [hand] => Some(hand),
+            [first, second] if first.beaten_by() == second => Some(second),
+            [first, _] => Some(first),
+            _ => None,
+        }
+    }
+}
+
+/// One of the two teams of a team game.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Team {
+    First,
+    Second,
 }
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -100,3 +145,182 @@ impl<MoveHash> Move<MoveHash> {
             .ok_or(())
     }
 }
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum MoveStatus {
+    Committed,
+    Revealed(Hand),
+}
+
+impl<MoveHash> From<&Move<MoveHash>> for MoveStatus {
+    fn from(player_move: &Move<MoveHash>) -> Self {
+        player_move.hand.map_or(MoveStatus::Committed, MoveStatus::Revealed)
+    }
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GameResult<AccountId, BlockNumber, Balance> {
+    pub ended_at: BlockNumber,
+    pub round_length: BlockNumber,
+    pub bet: Balance,
+    pub winners: BoundedVec<AccountId, MaxPlayers>,
+    pub players: BoundedVec<(AccountId, Option<Hand>, Balance), MaxPlayers>,
+    /// Whether the players can rematch: the game was played for the native currency without a
+    /// prize or teams.
+    pub rematchable: bool,
+}
+
+/// A move reveal signed by the player, submitted with `reveal_unsigned` without paying a fee.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RevealPayload<AccountId, GameId> {
+    pub player: AccountId,
+    pub game_id: GameId,
+    pub move_reveal: Vec<u8>,
+    /// Must match the number of the unsigned reveals of the player so far.
+    pub nonce: u32,
+}
+
+/// A rematch of an ended game waiting for all its players to commit their moves.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Rematch<AccountId, BlockNumber, Balance, MoveHash> {
+    pub round_length: BlockNumber,
+    pub bet: Balance,
+    pub players: Vec<AccountId>,
+    /// The block the rematch expires at unless all the players commit their moves.
+    pub expires_at: BlockNumber,
+    pub moves: Vec<(AccountId, MoveHash)>,
+}
+
+/// A schedule opening games with the same parameters at the given blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GameSchedule<AccountId, BlockNumber, Balance> {
+    pub owner: AccountId,
+    pub round_length: BlockNumber,
+    pub bet: Balance,
+    /// Number of blocks between the games, `None` if the schedule opens a single game.
+    pub period: Option<BlockNumber>,
+    /// Number of the games to open after the next one.
+    pub repeats: u32,
+    /// The block the next game opens at.
+    pub opens_at: BlockNumber,
+    /// The rest of the deposit reserved from the owner which the games not opened yet share.
+    pub deposit: Balance,
+}
+
+/// A challenge of the correspondence mode which any player can answer without a round length.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Challenge<AccountId, BlockNumber, Balance> {
+    pub challenger: AccountId,
+    pub stake: Balance,
+    /// The responder and its plaintext hand once the challenge is answered.
+    pub response: Option<(AccountId, Hand)>,
+    /// The block the challenger must reveal the move by once the challenge is answered.
+    pub deadline: Option<BlockNumber>,
+    /// The block the challenge is cancelled at unless it is answered before.
+    pub cancel_at: Option<BlockNumber>,
+}
+
+/// How the winner of a game is chosen if several players tie.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TieBreak {
+    /// All the tied players split the reward.
+    Split,
+    /// A single randomly drawn player of the tied ones wins.
+    Random,
+}
+
+impl Default for TieBreak {
+    fn default() -> Self {
+        TieBreak::Split
+    }
+}
+
+/// A game of a single player against the house.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct HouseGame<AccountId, BlockNumber, Balance> {
+    pub player: AccountId,
+    pub bet: Balance,
+    pub played_at: BlockNumber,
+    /// The block the house hand is drawn at or, once it is drawn, the reveal deadline.
+    pub deadline: BlockNumber,
+    pub house_hand: Option<Hand>,
+}
+
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PlayerStatistics {
+    pub games_played: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub forfeits: u32,
+    pub net_profit: i128,
+}
+
+impl PlayerStatistics {
+    /// Records a finished game. A player who did not reveal forfeits, a player who revealed but
+    /// got the bet back draws.
+    pub fn record(&mut self, hand: Option<Hand>, is_winner: bool, bet: u128, payout: u128) {
+        self.games_played = self.games_played.saturating_add(1);
+        match (hand, is_winner) {
+            (_, true) => self.wins = self.wins.saturating_add(1),
+            (None, false) => self.forfeits = self.forfeits.saturating_add(1),
+            (Some(_), false) if payout >= bet => self.draws = self.draws.saturating_add(1),
+            _ => {}
+        }
+
+        self.net_profit = self.net_profit.saturating_add(net_profit(bet, payout));
+    }
+}
+
+/// The net profit of a player who placed the `bet` and received the `payout`.
+pub fn net_profit(bet: u128, payout: u128) -> i128 {
+    i128::try_from(payout).unwrap_or(i128::MAX)
+        .saturating_sub(i128::try_from(bet).unwrap_or(i128::MAX))
+}
+
+/// The upper bound of `GameConfig::max_players`.
+pub type MaxPlayers = ConstU32<1_000>;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GameConfig<Balance> {
+    pub min_round_length: u32,
+    pub max_round_length: u32,
+    pub min_bet: Balance,
+    pub max_bet: Balance,
+    pub max_players: u32,
+    pub rake: Permill,
+}
+
+impl<Balance: Bounded> Default for GameConfig<Balance> {
+    fn default() -> Self {
+        Self {
+            min_round_length: 10,
+            max_round_length: 50,
+            min_bet: Balance::min_value(),
+            max_bet: Balance::max_value(),
+            max_players: 100,
+            rake: Permill::zero(),
+        }
+    }
+}
+
+impl<Balance: PartialOrd> GameConfig<Balance> {
+    /// The rake and the given jackpot share are both taken from the pot and must not exceed it.
+    pub fn is_valid(&self, jackpot_share: Permill) -> bool {
+        self.min_round_length > 0 &&
+            self.min_round_length <= self.max_round_length &&
+            self.min_bet <= self.max_bet &&
+            self.max_players >= 2 &&
+            self.max_players <= MaxPlayers::get() &&
+            self.rake.deconstruct().saturating_add(jackpot_share.deconstruct()) <= Permill::ACCURACY
+    }
+}
@@ -18,30 +18,142 @@
 //! - 0x02: Scissors
 //!
 //! The game ends when the last player reveals their move or when the round length is reached.
+//! The bets are held by the pallet account until the game ends.
+//!
+//! A game can also be played for an asset of `T::Assets` by starting it with `start_with_asset`.
+//! The rake of such games is kept by the pallet account until the `AdminOrigin` withdraws it with
+//! `withdraw_asset_rake`. The spending caps only limit the native currency bets.
+//!
+//! A payout which cannot be transferred when the game ends, for example of a frozen asset, is kept
+//! by the pallet account and the player can claim it later with `claim_payout`.
+//!
+//! A prize game is started with `start_with_prize` by escrowing an item of `T::Items` instead of
+//! a bet. The joiners pay the bet as usual. The item goes to a single winner chosen from the tied
+//! winners by the lowest hash of the game id and the account, and returns to the creator if nobody
+//! wins. An item which cannot be transferred when the game ends is kept by the pallet account until
+//! the winner or the creator claims it with `claim_prize`.
+//!
+//! A single player can play against the house with `play_house`. The hand of the house is drawn
+//! from `T::Randomness` `HouseDrawDelay` blocks later, after which the player has
+//! `HouseRoundLength` blocks to reveal the move with `reveal_house`. The bets are settled with the
+//! house account which keeps the `HouseEdge` of the winnings. An unrevealed bet is forfeited.
+//! Any account can fund the bankroll of the house from its own balance with `fund_house`. The
+//! `AdminOrigin` withdraws the part of it which is not needed to cover the winnings of the games in
+//! progress with `withdraw_house`. The
+//! winnings which cannot be transferred to the player are kept by the house until the player claims
+//! them with `claim_house_payout`.
+//!
+//! The creator of a game can switch it to a random tie-break with `set_tie_break` before anybody
+//! joins. A single winner of such a game is then drawn from the tied players using
+//! `T::Randomness` seeded with the game id, replacing the hash based choice of the prize winner.
+//! Such a game always ends at its reveal deadline and the draw waits until the randomness was not
+//! known before the deadline, so that no player can choose whether to reveal knowing the draw.
+//!
+//! A `JackpotShare` of the pot of every native currency game with winners feeds a progressive
+//! jackpot. The creator of such a game can also opt in with `set_jackpot_rollover` to roll the
+//! whole pot of the game over to the jackpot if nobody wins instead of refunding the bets. The
+//! jackpot is paid out to a sole winner of a game of at least `JackpotMinPlayers` players.
+//!
+//! Spectators can stake on a player of a game in "betting" stage winning with `side_bet`. The
+//! side-bets are settled parimutuel with the game: the spectators who backed a winner split all the
+//! stakes of the game in proportion to their stakes and the rounding remainder goes to the jackpot.
+//! The stakes are returned if nobody wins the game or nobody backed a winner.
+//!
+//! A team game started with `start_team` is played by two teams of the given size which the
+//! players join with `join_team`. A team game whose teams are not both full when the betting ends
+//! is cancelled and the bets are returned. The hand of a team is the majority of the revealed hands of
+//! its members, of two equally common hands the one beating the other. The revealed members of the
+//! team whose hand wins split the pot, a team without a hand loses to any team with one.
+//!
+//! The correspondence mode does not need the players to be online in the same window. A
+//! challenger posts a move hash and a stake with `post_challenge`, any player can answer it later
+//! with a plaintext hand and a matching stake with `respond_challenge`. The challenger then has
+//! `ChallengeRevealLength` blocks to reveal the move with `reveal_challenge` or forfeits the stakes.
+//! A challenge cancelled with `cancel_challenge` can still be answered for `ChallengeCancelDelay`
+//! blocks before the stake is returned.
+//!
+//! An account can schedule games to open at a future block with `schedule_game`, optionally
+//! recurring every given number of blocks for up to `MaxScheduleRepeats` repeats. The instances of
+//! a schedule are opened without players in `on_initialize` with the bet and the round length of
+//! the schedule and anyone can join them. The owner reserves a `ScheduleDeposit` which the opened
+//! games share, a game nobody joins by the end of its betting forfeits its share and the rest is
+//! returned when the schedule completes or is cancelled. At most `MaxSchedulesPerBlock` schedules
+//! open games at a block.
+//! The id of the next game of a schedule is reserved so no player can start a game with it.
+//!
+//! The players of a recently ended game can rematch it with `rematch`. The rematch is a game with
+//! the same players, bet and round length which only they can play. It starts in the "revealing"
+//! stage once all of them commit their moves within `RematchWindow` blocks, otherwise it expires
+//! and the bets are returned. The id of the rematch game is reserved once the first player commits.
+//!
+//! A player without the free balance to pay the fee can reveal with `reveal_unsigned`, an unsigned
+//! transaction carrying the reveal signed with the key of the player. The player signs the
+//! `reveal_signing_payload` of the reveal, which binds it to the pallet and the chain. Such a
+//! reveal is validated before entering the transaction pool and is only valid until the reveal
+//! deadline of the game.
+//!
+//! The native currency games are played in seasons of `SeasonLength` blocks. A `SeasonRakeShare`
+//! of the rake of such games feeds the season prize pool, the rest of the rake is burned. The
+//! leaderboard of the `LeaderboardSize` players with the highest net profit of the season is
+//! updated as their games end and at the end of a season the players on it share the prize pool by
+//! their rank. The net profits of the past seasons are removed `SeasonClearLimit` a block.
+//!
+//! The creator of a game reserves a `GameDeposit` which is returned when the game ends. If the game
+//! got to the "revealing" stage and the creator did not reveal their move the deposit is slashed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
     pallet_prelude::*,
-    traits::{Currency, ExistenceRequirement, WithdrawReasons},
+    PalletId,
+    traits::{
+        Currency,
+        ExistenceRequirement,
+        Randomness,
+        ReservableCurrency,
+        WithdrawReasons,
+        tokens::{fungibles, nonfungibles},
+    },
 };
 use frame_system::pallet_prelude::*;
 use sp_runtime::{
+    helpers_128bit::multiply_by_rational_with_rounding,
+    Perbill,
+    Permill,
+    PerThing,
+    Rounding,
     SaturatedConversion,
-    traits::{CheckedDiv, CheckEqual, Hash, MaybeDisplay, MaybeMallocSizeOf, Saturating, SimpleBitOps},
+    traits::{
+        AccountIdConversion, CheckedDiv, CheckedSub, One, CheckEqual, Hash, IdentifyAccount, MaybeDisplay, MaybeMallocSizeOf,
+        Saturating, SimpleBitOps, Verify, Zero,
+    },
+    transaction_validity::{
+        InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
+    },
 };
 use sp_std::{
+    collections::btree_map::BTreeMap,
     fmt::Debug,
     prelude::*,
 };
 
+pub use api::GameUpdate;
+pub use game::{Challenge, Game, GameConfig, GameResult, GameSchedule, GameStage, Hand, HouseGame, MaxPlayers, MoveStatus, PlayerStatistics, Rematch, RevealPayload, Team, TieBreak};
 pub use pallet::*;
+pub use rating::Rating;
+pub use traits::OnGameEnded;
 use weights::WeightInfo;
 
-use crate::game::{Game, GameStage, Move};
+use crate::game::{Move, net_profit};
+use crate::rating::Outcome;
 
+mod api;
 mod validation;
 mod game;
+mod rating;
+mod traits;
+#[cfg(any(feature = "try-runtime", test))]
+mod try_state;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 #[cfg(test)]
@@ -51,9 +163,51 @@ mod tests;
 pub mod weights;
 
 type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type AssetIdOf<T> = <<T as Config>::Assets as fungibles::Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+type CollectionIdOf<T> =
+    <<T as Config>::Items as nonfungibles::Inspect<<T as frame_system::Config>::AccountId>>::CollectionId;
+type ItemIdOf<T> = <<T as Config>::Items as nonfungibles::Inspect<<T as frame_system::Config>::AccountId>>::ItemId;
+type PrizeOf<T> = (<T as frame_system::Config>::AccountId, CollectionIdOf<T>, ItemIdOf<T>);
 type GameId<T> = <T as Config>::MoveHash;
 type GameOf<T> = Game<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>;
 type MoveOf<T> = Move<<T as Config>::MoveHash>;
+type GameConfigOf<T> = GameConfig<BalanceOf<T>>;
+type GameScheduleOf<T> = GameSchedule<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+>;
+/// The id of a schedule of games.
+pub type ScheduleId = u32;
+type GenesisGameOf<T> = (
+    <T as frame_system::Config>::AccountId,
+    GameId<T>,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+    Vec<(<T as frame_system::Config>::AccountId, <T as Config>::MoveHash)>,
+);
+type HouseGameOf<T> = HouseGame<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+>;
+type ChallengeOf<T> = Challenge<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+>;
+type RevealPayloadOf<T> = RevealPayload<<T as frame_system::Config>::AccountId, GameId<T>>;
+type RematchOf<T> = Rematch<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+    <T as Config>::MoveHash,
+>;
+type GameResultOf<T> = GameResult<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -90,15 +244,132 @@ pub mod pallet {
         type MoveHasher: Hash<Output=Self::MoveHash>;
 
         /// The currency trait.
-        type Currency: Currency<Self::AccountId>;
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The assets games can be played for besides the native `Currency`.
+        type Assets: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>>
+        + fungibles::Transfer<Self::AccountId>;
+
+        /// The items which can be escrowed as the prizes of the games.
+        type Items: nonfungibles::Inspect<Self::AccountId> + nonfungibles::Transfer<Self::AccountId>;
+
+        /// The pallet id, used for deriving the account holding the bets of the asset games.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// The deposit reserved from the owner of a game until it ends. It is slashed if the owner
+        /// does not reveal the move.
+        #[pallet::constant]
+        type GameDeposit: Get<BalanceOf<Self>>;
+
+        /// The origin which may update the game parameters.
+        type AdminOrigin: EnsureOrigin<Self::Origin>;
+
+        /// Number of blocks the results of an ended game are kept for.
+        #[pallet::constant]
+        type ResultsRetention: Get<u32>;
+
+        /// Maximal rating change after a duel.
+        #[pallet::constant]
+        type RatingKFactor: Get<u32>;
+
+        /// Maximal rating change after a duel while the rating is provisional.
+        #[pallet::constant]
+        type ProvisionalKFactor: Get<u32>;
+
+        /// Number of duels a rating stays provisional for.
+        #[pallet::constant]
+        type ProvisionalGames: Get<u32>;
+
+        /// The share of the rake which goes to the season prize pool. The rest of the rake is
+        /// burned.
+        #[pallet::constant]
+        type SeasonRakeShare: Get<Permill>;
+
+        /// Season length in blocks.
+        #[pallet::constant]
+        type SeasonLength: Get<u32>;
+
+        /// Maximum number of players on the season leaderboard.
+        #[pallet::constant]
+        type LeaderboardSize: Get<u32>;
+
+        /// Maximum number of the net profits of the past seasons removed in a block.
+        #[pallet::constant]
+        type SeasonClearLimit: Get<u32>;
+
+        /// Source of randomness for the hands of the house and the random tie-breaks.
+        type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+        /// The share of the winnings the house keeps when a player beats it.
+        #[pallet::constant]
+        type HouseEdge: Get<Permill>;
+
+        /// Number of blocks after a game against the house is started its hand is drawn at.
+        #[pallet::constant]
+        type HouseDrawDelay: Get<u32>;
+
+        /// Number of blocks a player has to reveal the move once the house hand is drawn.
+        #[pallet::constant]
+        type HouseRoundLength: Get<u32>;
+
+        /// Number of blocks all the players of a rematch have to commit their moves within.
+        #[pallet::constant]
+        type RematchWindow: Get<u32>;
+
+        /// Maximum number of the repeats of a recurring schedule of games.
+        #[pallet::constant]
+        type MaxScheduleRepeats: Get<u32>;
+
+        /// The deposit reserved from the owner of a schedule of games until it completes or is
+        /// cancelled.
+        #[pallet::constant]
+        type ScheduleDeposit: Get<BalanceOf<Self>>;
+
+        /// Maximum number of the schedules opening a game at the same block.
+        #[pallet::constant]
+        type MaxSchedulesPerBlock: Get<u32>;
+
+        /// Number of blocks a challenger has to reveal the move once the challenge is answered.
+        #[pallet::constant]
+        type ChallengeRevealLength: Get<u32>;
+
+        /// Number of blocks a challenge can still be answered after the challenger cancels it.
+        #[pallet::constant]
+        type ChallengeCancelDelay: Get<u32>;
+
+        /// The share of the pot of every native currency game with winners feeding the jackpot.
+        #[pallet::constant]
+        type JackpotShare: Get<Permill>;
+
+        /// Minimum number of players of a game its sole winner wins the jackpot in.
+        #[pallet::constant]
+        type JackpotMinPlayers: Get<u32>;
+
+        /// Maximum number of side-bets on a single game.
+        #[pallet::constant]
+        type MaxSideBets: Get<u32>;
+
+        /// The signature of the reveal payloads submitted with `reveal_unsigned`.
+        type Signature: Verify<Signer = Self::Signer> + Parameter;
+
+        /// The key identifying the account which signed a reveal payload.
+        type Signer: IdentifyAccount<AccountId = Self::AccountId>;
 
-        /// Minimal round length.
+        /// The base priority of the unsigned reveals, the closer to the reveal deadline the higher.
         #[pallet::constant]
-        type MinRoundLength: Get<u32>;
+        type UnsignedRevealPriority: Get<TransactionPriority>;
 
-        /// Maximum round length.
+        /// Maximum number of games in progress an account may play at once.
         #[pallet::constant]
-        type MaxRoundLength: Get<u32>;
+        type MaxActiveGamesPerAccount: Get<u32>;
+
+        /// Length in blocks of the period the spending caps of the accounts apply to.
+        #[pallet::constant]
+        type SpendingPeriod: Get<u32>;
+
+        /// Handler for the end of a game.
+        type OnGameEnded: OnGameEnded<Self::AccountId, GameId<Self>, BalanceOf<Self>>;
 
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
@@ -107,14 +378,174 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// Game started. \[game_id, owner, round_length, bet\]
-        GameStarted { game_id: GameId<T>, owner: T::AccountId, round_length: T::BlockNumber, bet: BalanceOf<T> },
+        /// Game started. \[game_id, owner, round_length, bet, asset, deposit, schedule\]
+        ///
+        /// `asset` is `None` for the games played for the native currency. `schedule` is the
+        /// schedule which opened the game, if any.
+        GameStarted {
+            game_id: GameId<T>,
+            owner: T::AccountId,
+            round_length: T::BlockNumber,
+            bet: BalanceOf<T>,
+            asset: Option<AssetIdOf<T>>,
+            deposit: BalanceOf<T>,
+            schedule: Option<ScheduleId>,
+        },
         /// Bet placed. \[game_id, player\]
         BetPlaced { game_id: GameId<T>, player: T::AccountId },
         /// Move revealed. \[game_id, player\]
         MoveRevealed { game_id: GameId<T>, player: T::AccountId },
-        /// Game ended. \[game_id, winners, reward\]
-        GameEnded { game_id: GameId<T>, winners: Vec<T::AccountId>, reward: BalanceOf<T> },
+        /// Game ended. \[game_id, winners, reward, players\]
+        ///
+        /// `players` holds the revealed hand and the payout of every player.
+        GameEnded {
+            game_id: GameId<T>,
+            winners: Vec<T::AccountId>,
+            reward: BalanceOf<T>,
+            players: Vec<(T::AccountId, Option<Hand>, BalanceOf<T>)>,
+        },
+        /// Revealing started. \[game_id, deadline\]
+        RevealingStarted { game_id: GameId<T>, deadline: T::BlockNumber },
+        /// Nobody won, all bets returned. \[game_id, players, amount\]
+        Refunded { game_id: GameId<T>, players: Vec<T::AccountId>, amount: BalanceOf<T> },
+        /// Season ended and the prizes were paid out. \[season, prizes\]
+        SeasonEnded { season: u32, prizes: Vec<(T::AccountId, BalanceOf<T>)> },
+        /// Game parameters updated. \[config\]
+        ConfigUpdated { config: GameConfigOf<T> },
+        /// New games paused.
+        Paused,
+        /// New games unpaused.
+        Unpaused,
+        /// Game ended by the admin. \[game_id\]
+        ForceEnded { game_id: GameId<T> },
+        /// Game cancelled by the admin, all bets returned. \[game_id, players, amount\]
+        ForceRefunded { game_id: GameId<T>, players: Vec<T::AccountId>, amount: BalanceOf<T> },
+        /// Game deposit returned to the owner. \[game_id, owner, amount\]
+        DepositReleased { game_id: GameId<T>, owner: T::AccountId, amount: BalanceOf<T> },
+        /// Game deposit slashed as the owner did not reveal the move. \[game_id, owner, amount\]
+        DepositSlashed { game_id: GameId<T>, owner: T::AccountId, amount: BalanceOf<T> },
+        /// Account excluded itself from playing. \[player, until\]
+        SelfExcluded { player: T::AccountId, until: T::BlockNumber },
+        /// Account spending cap changed. \[player, cap\]
+        SpendingCapSet { player: T::AccountId, cap: Option<BalanceOf<T>> },
+        /// Prize item escrowed for a game. \[game_id, collection, item\]
+        PrizeEscrowed { game_id: GameId<T>, collection: CollectionIdOf<T>, item: ItemIdOf<T> },
+        /// Prize item transferred to the winner. \[game_id, winner, collection, item\]
+        PrizeAwarded {
+            game_id: GameId<T>,
+            winner: T::AccountId,
+            collection: CollectionIdOf<T>,
+            item: ItemIdOf<T>,
+        },
+        /// Nobody won, prize item returned to the owner. \[game_id, owner, collection, item\]
+        PrizeReturned {
+            game_id: GameId<T>,
+            owner: T::AccountId,
+            collection: CollectionIdOf<T>,
+            item: ItemIdOf<T>,
+        },
+        /// Game against the house started. \[game_id, player, bet, draw_at\]
+        HouseGameStarted { game_id: GameId<T>, player: T::AccountId, bet: BalanceOf<T>, draw_at: T::BlockNumber },
+        /// House hand drawn, the player may reveal the move. \[game_id, deadline\]
+        HouseHandDrawn { game_id: GameId<T>, deadline: T::BlockNumber },
+        /// Game against the house ended. \[game_id, player, hand, house_hand, payout\]
+        ///
+        /// `hand` is `None` if the player did not reveal the move and forfeited the bet.
+        HouseGameEnded {
+            game_id: GameId<T>,
+            player: T::AccountId,
+            hand: Option<Hand>,
+            house_hand: Hand,
+            payout: BalanceOf<T>,
+        },
+        /// Game tie-break changed by the owner. \[game_id, tie_break\]
+        TieBreakSet { game_id: GameId<T>, tie_break: TieBreak },
+        /// Single winner of the tied players drawn. \[game_id, seed, tied, winner\]
+        ///
+        /// The winner is the tied player at the index of the seed modulo the number of the tied
+        /// players, in the order of `tied`.
+        TieBroken { game_id: GameId<T>, seed: T::Hash, tied: Vec<T::AccountId>, winner: T::AccountId },
+        /// Game pot rollover to the jackpot changed by the owner. \[game_id, rollover\]
+        JackpotRolloverSet { game_id: GameId<T>, rollover: bool },
+        /// Part of the pot of the game added to the jackpot. \[game_id, amount, jackpot\]
+        JackpotIncreased { game_id: GameId<T>, amount: BalanceOf<T>, jackpot: BalanceOf<T> },
+        /// Jackpot paid out to the sole winner of the game. \[game_id, winner, amount\]
+        JackpotWon { game_id: GameId<T>, winner: T::AccountId, amount: BalanceOf<T> },
+        /// Spectator staked on a player winning the game. \[game_id, spectator, player, stake\]
+        SideBetPlaced { game_id: GameId<T>, spectator: T::AccountId, player: T::AccountId, stake: BalanceOf<T> },
+        /// Side-bets of the game settled. \[game_id, payouts\]
+        SideBetsSettled { game_id: GameId<T>, payouts: Vec<(T::AccountId, BalanceOf<T>)> },
+        /// Team game started. \[game_id, team_size\]
+        TeamGameStarted { game_id: GameId<T>, team_size: u32 },
+        /// Player joined a team of the game. \[game_id, player, team\]
+        TeamJoined { game_id: GameId<T>, player: T::AccountId, team: Team },
+        /// Challenge posted. \[game_id, challenger, stake\]
+        ChallengePosted { game_id: GameId<T>, challenger: T::AccountId, stake: BalanceOf<T> },
+        /// Challenge cancelled by the challenger, the stake returned. \[game_id\]
+        ChallengeCancelled { game_id: GameId<T> },
+        /// Challenge answered, the challenger must reveal the move. \[game_id, responder, hand, deadline\]
+        ChallengeAnswered { game_id: GameId<T>, responder: T::AccountId, hand: Hand, deadline: T::BlockNumber },
+        /// Challenge ended. \[game_id, challenger_hand, responder_hand, winner, payout\]
+        ///
+        /// `challenger_hand` is `None` if the challenger did not reveal the move and forfeited. The
+        /// `payout` goes to the `winner` or to each of the players if there is none.
+        ChallengeEnded {
+            game_id: GameId<T>,
+            challenger_hand: Option<Hand>,
+            responder_hand: Hand,
+            winner: Option<T::AccountId>,
+            payout: BalanceOf<T>,
+        },
+        /// Games scheduled. \[schedule_id, owner, opens_at\]
+        GameScheduled { schedule_id: ScheduleId, owner: T::AccountId, opens_at: T::BlockNumber },
+        /// Schedule cancelled by the owner. \[schedule_id\]
+        ScheduleCancelled { schedule_id: ScheduleId },
+        /// Schedule opened its last game. \[schedule_id\]
+        ScheduleCompleted { schedule_id: ScheduleId },
+        /// Player committed a move to the rematch of the game. \[game_id, player\]
+        RematchCommitted { game_id: GameId<T>, player: T::AccountId },
+        /// Rematch of the previous game started. \[game_id, previous\]
+        RematchStarted { game_id: GameId<T>, previous: GameId<T> },
+        /// Rematch of the game expired, the bets returned. \[game_id, players, amount\]
+        RematchExpired { game_id: GameId<T>, players: Vec<T::AccountId>, amount: BalanceOf<T> },
+        /// Account spending cap raised or removed from the next spending period. \[player, cap, applies_at\]
+        SpendingCapPending { player: T::AccountId, cap: Option<BalanceOf<T>>, applies_at: T::BlockNumber },
+        /// Payout could not be transferred, the player may claim it. \[player, asset, amount\]
+        PayoutUnclaimed { player: T::AccountId, asset: Option<AssetIdOf<T>>, amount: BalanceOf<T> },
+        /// Unclaimed payout transferred to the player. \[player, asset, amount\]
+        PayoutClaimed { player: T::AccountId, asset: Option<AssetIdOf<T>>, amount: BalanceOf<T> },
+        /// Rake of the asset games withdrawn by the admin. \[asset, dest, amount\]
+        AssetRakeWithdrawn { asset: AssetIdOf<T>, dest: T::AccountId, amount: BalanceOf<T> },
+        /// Prize item could not be transferred, the player may claim it. \[game_id, player, collection, item\]
+        PrizeUnclaimed {
+            game_id: GameId<T>,
+            player: T::AccountId,
+            collection: CollectionIdOf<T>,
+            item: ItemIdOf<T>,
+        },
+        /// Unclaimed prize item transferred to the player. \[game_id, player, collection, item\]
+        PrizeClaimed {
+            game_id: GameId<T>,
+            player: T::AccountId,
+            collection: CollectionIdOf<T>,
+            item: ItemIdOf<T>,
+        },
+        /// House bankroll funded by the source account. \[source, amount\]
+        HouseFunded { source: T::AccountId, amount: BalanceOf<T> },
+        /// House bankroll withdrawn by the admin. \[dest, amount\]
+        HouseWithdrawn { dest: T::AccountId, amount: BalanceOf<T> },
+        /// Winnings of a game against the house could not be transferred, the player may claim
+        /// them. \[game_id, player, amount\]
+        HouseDebtIncurred { game_id: GameId<T>, player: T::AccountId, amount: BalanceOf<T> },
+        /// Winnings owed by the house transferred to the player. \[player, amount\]
+        HouseDebtPaid { player: T::AccountId, amount: BalanceOf<T> },
+        /// Challenge cancellation requested, it can be answered until then. \[game_id, cancel_at\]
+        ChallengeCancelRequested { game_id: GameId<T>, cancel_at: T::BlockNumber },
+        /// Scheduled game not opened. \[schedule_id, game_id\]
+        ///
+        /// A game is skipped if the games are paused, the schedule parameters are no longer valid,
+        /// its id is taken or its block has no room for the schedule.
+        ScheduledGameSkipped { schedule_id: ScheduleId, game_id: GameId<T> },
     }
 
     #[pallet::error]
@@ -137,12 +568,218 @@ pub mod pallet {
         PlayerMoveInvalid,
         /// Player move reveal does not match with the move hash
         PlayerRevealMismatch,
+        /// Bet is out of the allowed range
+        BetInvalid,
+        /// Game has no free seats
+        GameFull,
+        /// Game parameters are inconsistent
+        ConfigInvalid,
+        /// New games are paused
+        GamesPaused,
+        /// Player is in too many games at once
+        TooManyActiveGames,
+        /// Player excluded themselves from playing
+        PlayerSelfExcluded,
+        /// Self-exclusion can only be extended
+        SelfExclusionTooShort,
+        /// Bet exceeds the player spending cap for the period
+        SpendingCapExceeded,
+        /// Prize item is not owned by the game owner
+        PrizeNotOwned,
+        /// House cannot cover the winnings of the bet
+        HouseBankrollLow,
+        /// House hand is not drawn yet
+        HouseHandNotReady,
+        /// Only the owner of the game can do this
+        NotGameOwner,
+        /// Game already has players other than its owner
+        GameAlreadyJoined,
+        /// Only the native currency games without a prize take part in the jackpot
+        JackpotUnavailable,
+        /// Spectator already placed a side-bet on the game
+        SideBetPlaced,
+        /// Players cannot side-bet on their own game and spectators cannot join it
+        SideBetConflict,
+        /// Team size is invalid
+        TeamSizeInvalid,
+        /// Team is full
+        TeamFull,
+        /// Team game can only be joined with a team
+        TeamRequired,
+        /// Game is not a team game
+        NotTeamGame,
+        /// Challenge is already answered
+        ChallengeAnswered,
+        /// Challenge is not answered yet
+        ChallengeNotAnswered,
+        /// Challenger cannot answer their own challenge
+        ChallengeOwnAnswer,
+        /// Schedule must open the first game at a future block and repeat only with a period
+        ScheduleInvalid,
+        /// Schedule does not exist
+        ScheduleMissing,
+        /// Game cannot be rematched
+        RematchUnavailable,
+        /// Only the players of the game can do this
+        NotGamePlayer,
+        /// Game can only be played by the players of the previous game
+        GameClosed,
+        /// Nonce of the unsigned reveal does not match the number of the reveals of the player
+        RevealNonceInvalid,
+        /// There is no payout to claim
+        PayoutMissing,
+        /// There is no rake of the asset to withdraw
+        RakeMissing,
+        /// There is no prize item of the game to claim
+        PrizeMissing,
+        /// The house owes no winnings to the player
+        HouseDebtMissing,
+        /// Game already has the maximum number of side-bets
+        TooManySideBets,
+        /// Challenge cancellation is already requested
+        ChallengeCancelling,
+        /// Block already opens the maximum number of scheduled games
+        ScheduleBlockFull,
+        /// Team games are decided by the team hands and can not break ties
+        TieBreakUnavailable,
+    }
+
+    /// The game parameters.
+    #[pallet::storage]
+    pub type PalletConfig<T: Config> = StorageValue<_, GameConfigOf<T>, ValueQuery>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        pub config: GameConfigOf<T>,
+        /// Games in "betting" stage to start at genesis as `(owner, game_id, round_length, bet,
+        /// joiners)`. The game id is the move hash of the owner, the joiners are given with their
+        /// move hashes. The bets are transferred to the pallet account.
+        pub games: Vec<GenesisGameOf<T>>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self { config: Default::default(), games: Default::default() }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            assert!(self.config.is_valid(T::JackpotShare::get()), "Game parameters are inconsistent");
+            PalletConfig::<T>::put(&self.config);
+
+            // the pallet account must exist to receive the bets of the asset games and stay alive
+            // while paying out the native ones
+            let account_id = Pallet::<T>::account_id();
+            if T::Currency::free_balance(&account_id) < T::Currency::minimum_balance() {
+                let _ = T::Currency::make_free_balance_be(&account_id, T::Currency::minimum_balance());
+            }
+
+            for (owner, game_id, round_length, bet, joiners) in &self.games {
+                assert!(
+                    Pallet::<T>::valid_round_length(&self.config, *round_length),
+                    "Genesis game round length is invalid"
+                );
+                assert!(Pallet::<T>::valid_bet(&self.config, *bet), "Genesis game bet is invalid");
+                assert!(Pallet::<T>::can_create_game(game_id), "Genesis game id is duplicated");
+
+                Pallet::<T>::deposit_bet(owner, None, *bet).expect("Genesis game owner can afford the bet");
+                Pallet::<T>::reserve_deposit(owner, game_id).expect("Genesis game owner can afford the deposit");
+                Pallet::<T>::start_game(owner, *game_id, *round_length, *bet);
+
+                for (joiner, move_hash) in joiners {
+                    let game = Games::<T>::get(game_id).expect("Genesis game was just started");
+                    assert!(
+                        Pallet::<T>::has_free_seat(&self.config, &game),
+                        "Genesis game has too many players"
+                    );
+                    assert!(
+                        Pallet::<T>::can_make_move(game_id, joiner),
+                        "Genesis game player is duplicated"
+                    );
+
+                    Pallet::<T>::deposit_bet(joiner, None, *bet).expect("Genesis game player can afford the bet");
+                    Pallet::<T>::join_game(game_id, joiner, *move_hash);
+                }
+            }
+        }
     }
 
     /// The games currently in progress.
     #[pallet::storage]
     pub type Games<T> = StorageMap<_, Blake2_128Concat, GameId<T>, GameOf<T>, OptionQuery>;
 
+    /// The assets of the games in progress which are not played for the native currency.
+    #[pallet::storage]
+    pub type GameAssets<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, AssetIdOf<T>, OptionQuery>;
+
+    /// The owners and the escrowed prize items of the prize games in progress. A prize item which
+    /// could not be transferred when the game ended is kept with the account to claim it.
+    #[pallet::storage]
+    pub type GamePrizes<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        PrizeOf<T>,
+        OptionQuery
+    >;
+
+    /// The team sizes of the team games in progress.
+    #[pallet::storage]
+    pub type TeamGames<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, u32, OptionQuery>;
+
+    /// The teams of the players of the team games in progress.
+    #[pallet::storage]
+    pub type TeamMembers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        Team,
+        OptionQuery
+    >;
+
+    /// The tie-breaks of the games in progress which do not split the reward.
+    #[pallet::storage]
+    pub type GameTieBreaks<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, TieBreak, OptionQuery>;
+
+    /// The games in progress whose pot rolls over to the jackpot if nobody wins.
+    #[pallet::storage]
+    pub type JackpotRollovers<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, (), OptionQuery>;
+
+    /// The side-bets on the games in progress: the backed player and the stake of every spectator.
+    #[pallet::storage]
+    pub type SideBets<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::AccountId, BalanceOf<T>),
+        OptionQuery
+    >;
+
+    /// The number of the side-bets on the games in progress.
+    #[pallet::storage]
+    pub type SideBetCounts<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, u32, ValueQuery>;
+
+    /// The progressive jackpot paid out to the next sole winner of a large enough game.
+    #[pallet::storage]
+    pub type Jackpot<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// The deposits reserved from the owners of the games in progress.
+    #[pallet::storage]
+    pub type GameDeposits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        GameId<T>,
+        (T::AccountId, BalanceOf<T>),
+        OptionQuery
+    >;
+
     /// Index of all the active games in "betting" stage by their expiration block number.
     #[pallet::storage]
     pub type BettingGamesIndex<T: Config> = StorageMap<
@@ -175,10 +812,207 @@ pub mod pallet {
         OptionQuery
     >;
 
+    /// Whether starting and joining games is paused.
+    #[pallet::storage]
+    pub type Paused<T> = StorageValue<_, bool, ValueQuery>;
+
+    /// The games against the house in progress.
+    #[pallet::storage]
+    pub type HouseGames<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, HouseGameOf<T>, OptionQuery>;
+
+    /// Index of the games against the house by their draw block or their reveal deadline.
+    #[pallet::storage]
+    pub type HouseGamesIndex<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        Vec<GameId<T>>,
+        OptionQuery
+    >;
+
+    /// Total winnings the house may have to pay out for the games against it in progress and the
+    /// winnings it owes to the players.
+    #[pallet::storage]
+    pub type HouseLiability<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// The winnings of the games against the house which could not be transferred to the player.
+    #[pallet::storage]
+    pub type HouseDebts<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// The rematches waiting for the moves of the players by the previous game.
+    #[pallet::storage]
+    pub type Rematches<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, RematchOf<T>, OptionQuery>;
+
+    /// Index of the rematches by their expiry block.
+    #[pallet::storage]
+    pub type RematchesIndex<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        Vec<GameId<T>>,
+        OptionQuery
+    >;
+
+    /// The previous games of the rematches in progress.
+    #[pallet::storage]
+    pub type RematchGames<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, GameId<T>, OptionQuery>;
+
+    /// The id of the next schedule of games.
+    #[pallet::storage]
+    pub type NextScheduleId<T> = StorageValue<_, ScheduleId, ValueQuery>;
+
+    /// The schedules of games which still have games to open.
+    #[pallet::storage]
+    pub type Schedules<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, GameScheduleOf<T>, OptionQuery>;
+
+    /// Index of the schedules by the block they open the next game at.
+    #[pallet::storage]
+    pub type SchedulesIndex<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        Vec<ScheduleId>,
+        OptionQuery
+    >;
+
+    /// The ids of the games the pallet opens itself which no player can start a game with.
+    #[pallet::storage]
+    pub type ReservedGameIds<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, (), OptionQuery>;
+
+    /// The challenges of the correspondence mode in progress.
+    #[pallet::storage]
+    pub type Challenges<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, ChallengeOf<T>, OptionQuery>;
+
+    /// Index of the answered challenges by their reveal deadline and of the cancelled ones by
+    /// the block they are cancelled at.
+    #[pallet::storage]
+    pub type ChallengesIndex<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        Vec<GameId<T>>,
+        OptionQuery
+    >;
+
+    /// Number of the games in progress each account plays.
+    #[pallet::storage]
+    pub type ActiveGames<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// The block until which each account excluded itself from playing.
+    #[pallet::storage]
+    pub type SelfExclusions<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+    /// Number of the unsigned reveals of each account, the nonce of its next reveal payload.
+    #[pallet::storage]
+    pub type RevealNonces<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// The maximal amount each account allowed itself to bet per `SpendingPeriod`.
+    #[pallet::storage]
+    pub type SpendingCaps<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+    /// The raised or removed spending caps by account with the spending period index they apply
+    /// from.
+    #[pallet::storage]
+    pub type PendingSpendingCaps<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::BlockNumber, Option<BalanceOf<T>>),
+        OptionQuery
+    >;
+
+    /// The spending period index and the amount bet within it by each account with a spending cap.
+    #[pallet::storage]
+    pub type Spendings<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::BlockNumber, BalanceOf<T>),
+        OptionQuery
+    >;
+
+    /// Total amount of native currency bets the pallet account holds for all the active games.
+    #[pallet::storage]
+    pub type Escrow<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// The payouts the pallet account failed to transfer to each account by the asset they are in,
+    /// `None` for the native currency.
+    #[pallet::storage]
+    pub type UnclaimedPayouts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        Option<AssetIdOf<T>>,
+        BalanceOf<T>,
+        ValueQuery
+    >;
+
+    /// The rake of the asset games the pallet account holds until the admin withdraws it.
+    #[pallet::storage]
+    pub type AssetRakes<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+    /// The results of the recently ended games.
+    #[pallet::storage]
+    pub type GameResults<T: Config> = StorageMap<_, Blake2_128Concat, GameId<T>, GameResultOf<T>, OptionQuery>;
+
+    /// Index of the game results by their expiration block number.
+    #[pallet::storage]
+    pub type GameResultsIndex<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::BlockNumber,
+        Vec<GameId<T>>,
+        OptionQuery
+    >;
+
+    /// The statistics of all the games played by an account.
+    #[pallet::storage]
+    pub type PlayerStats<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, PlayerStatistics, ValueQuery>;
+
+    /// The skill ratings of the accounts based on the duels they played.
+    #[pallet::storage]
+    pub type Ratings<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Rating, ValueQuery>;
+
+    /// The current season.
+    #[pallet::storage]
+    pub type CurrentSeason<T> = StorageValue<_, u32, ValueQuery>;
+
+    /// The prize pool of the current season.
+    #[pallet::storage]
+    pub type SeasonPrizePool<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// The net profit of the players in a season.
+    #[pallet::storage]
+    pub type SeasonProfits<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        u32,
+        Blake2_128Concat,
+        T::AccountId,
+        i128,
+        ValueQuery
+    >;
+
+    /// The players of the current season with the highest net profit in descending order. Only
+    /// updated for the players who finish a game, the season prizes are paid by the standings on
+    /// it when the season ends.
+    #[pallet::storage]
+    pub type Leaderboard<T: Config> = StorageValue<
+        _,
+        BoundedVec<(T::AccountId, i128), T::LeaderboardSize>,
+        ValueQuery
+    >;
+
+    /// The first season whose `SeasonProfits` may remain. The profits of the past seasons are
+    /// removed up to `SeasonClearLimit` a block.
+    #[pallet::storage]
+    pub type ClearedSeasons<T> = StorageValue<_, u32, ValueQuery>;
+
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: T::BlockNumber) -> Weight {
-            let mut weight = T::DbWeight::get().reads(2);
+            let mut weight = T::DbWeight::get().reads(3);
 
             BettingGamesIndex::<T>::mutate_exists(now, |maybe_game_ids| {
                 if let Some(game_ids) = maybe_game_ids.take() {
@@ -186,7 +1020,7 @@ pub mod pallet {
                         T::WeightInfo::on_initialize_betting(game_ids.len() as u32));
 
                     for game_id in game_ids {
-                        Self::end_betting(&game_id);
+                        weight = weight.saturating_add(Self::end_betting(&game_id));
                     }
                 }
             });
@@ -195,21 +1029,93 @@ pub mod pallet {
                 if let Some(game_ids) = maybe_game_ids.take() {
                     weight = weight.saturating_add(
                         T::WeightInfo::on_initialize_revealing(game_ids.len() as u32));
+                    weight = weight.saturating_add(
+                        T::OnGameEnded::weight(MaxPlayers::get())
+                            .saturating_add(T::WeightInfo::settle_side_bets(T::MaxSideBets::get()))
+                            .saturating_mul(game_ids.len() as Weight));
 
                     for game_id in game_ids {
-                        Self::end_game(&game_id);
+                        Self::end_revealing(&game_id, now);
                     }
                 }
             });
 
-            weight
-        }
-    }
+            // taken up front as the processed games are indexed again at later blocks
+            if let Some(game_ids) = HouseGamesIndex::<T>::take(now) {
+                weight = weight.saturating_add(
+                    T::WeightInfo::on_initialize_house(game_ids.len() as u32));
+
+                for game_id in game_ids {
+                    Self::process_house_game(&game_id, now);
+                }
+            }
+
+            ChallengesIndex::<T>::mutate_exists(now, |maybe_game_ids| {
+                if let Some(game_ids) = maybe_game_ids.take() {
+                    weight = weight.saturating_add(
+                        T::WeightInfo::on_initialize_challenges(game_ids.len() as u32));
+
+                    for game_id in game_ids {
+                        Self::expire_challenge(&game_id);
+                    }
+                }
+            });
+
+            if let Some(game_ids) = RematchesIndex::<T>::take(now) {
+                weight = weight.saturating_add(
+                    T::WeightInfo::on_initialize_rematches(game_ids.len() as u32));
+
+                for game_id in game_ids {
+                    Self::expire_rematch(&game_id);
+                }
+            }
+
+            if let Some(schedule_ids) = SchedulesIndex::<T>::take(now) {
+                weight = weight.saturating_add(
+                    T::WeightInfo::on_initialize_schedules(schedule_ids.len() as u32));
+                // the blocks of the skipped games of every schedule
+                weight = weight.saturating_add(T::DbWeight::get().reads(
+                    (T::MaxScheduleRepeats::get() as Weight).saturating_mul(schedule_ids.len() as Weight)));
+
+                for schedule_id in schedule_ids {
+                    Self::open_scheduled_game(schedule_id, now);
+                }
+            }
+
+            let season_length: T::BlockNumber = T::SeasonLength::get().into();
+            if !season_length.is_zero() && (now % season_length).is_zero() {
+                weight = weight.saturating_add(Self::end_season());
+            }
+            weight = weight.saturating_add(Self::clear_past_season());
+
+            GameResultsIndex::<T>::mutate_exists(now, |maybe_game_ids| {
+                if let Some(game_ids) = maybe_game_ids.take() {
+                    weight = weight.saturating_add(
+                        T::DbWeight::get().reads_writes(game_ids.len() as Weight, game_ids.len() as Weight));
+
+                    for game_id in game_ids {
+                        Self::prune_result(&game_id, now);
+                    }
+                }
+            });
+
+            weight
+        }
+
+        // The `Hooks` of polkadot-v0.9.28 have no `try_state` hook run after every block by
+        // try-runtime, so the invariants are only checked after a runtime upgrade and after every
+        // test of the mock runtime. They move to `try_state` with the upgrade to a release having it.
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade() -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Start a new game. The game will be created in "betting" stage. A creator needs to
         /// provide a round length, a bet amount and a move hash. The move hash becomes an game id.
+        /// The `GameDeposit` is reserved from the creator until the game ends.
         #[pallet::weight(T::WeightInfo::start_game())]
         pub fn start(
             origin: OriginFor<T>,
@@ -218,74 +1124,1094 @@ pub mod pallet {
             move_hash: T::MoveHash,
         ) -> DispatchResult {
             let owner = ensure_signed(origin)?;
-            ensure!(Self::valid_round_length(round_length), Error::<T>::RoundLengthInvalid);
+            Self::do_start(owner, round_length, bet, None, None, move_hash)
+        }
+
+        /// Start a new game played for the given asset. The game bet is in the units of the asset,
+        /// the bet limits of the game parameters only apply to the native currency games.
+        #[pallet::weight(T::WeightInfo::start_game_with_asset())]
+        pub fn start_with_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] round_length: T::BlockNumber,
+            #[pallet::compact] bet: BalanceOf<T>,
+            asset: AssetIdOf<T>,
+            move_hash: T::MoveHash,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            Self::do_start(owner, round_length, bet, Some(asset), None, move_hash)
+        }
+
+        /// Start a new game with the given item as the prize. The item is escrowed until the game
+        /// ends instead of a bet of the creator, the joiners pay the bet.
+        #[pallet::weight(T::WeightInfo::start_game_with_prize())]
+        pub fn start_with_prize(
+            origin: OriginFor<T>,
+            #[pallet::compact] round_length: T::BlockNumber,
+            #[pallet::compact] bet: BalanceOf<T>,
+            collection: CollectionIdOf<T>,
+            item: ItemIdOf<T>,
+            move_hash: T::MoveHash,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            Self::do_start(owner, round_length, bet, None, Some((collection, item)), move_hash)
+        }
+
+        /// Place a bet on an existing game. The game must be in "betting" stage. A player needs to
+        /// provide a game id and a move hash.
+        #[pallet::weight(T::WeightInfo::join_game().max(T::WeightInfo::join_game_with_asset()))]
+        pub fn join(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            move_hash: T::MoveHash,
+        ) -> DispatchResult {
+            let joiner = ensure_signed(origin)?;
+            ensure!(!TeamGames::<T>::contains_key(&game_id), Error::<T>::TeamRequired);
+            ensure!(!RematchGames::<T>::contains_key(&game_id), Error::<T>::GameClosed);
+            Self::do_join(joiner, game_id, move_hash)
+        }
+
+        /// Start a new team game of two teams of `team_size` players. The creator joins the first
+        /// team, the other players join with `join_team`. The game is cancelled and refunded if
+        /// the teams are not both full when the betting ends.
+        #[pallet::weight(T::WeightInfo::start_team_game())]
+        pub fn start_team(
+            origin: OriginFor<T>,
+            #[pallet::compact] round_length: T::BlockNumber,
+            #[pallet::compact] bet: BalanceOf<T>,
+            team_size: u32,
+            move_hash: T::MoveHash,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            ensure!(Self::valid_team_size(&PalletConfig::<T>::get(), team_size), Error::<T>::TeamSizeInvalid);
+            Self::do_start(owner.clone(), round_length, bet, None, None, move_hash)?;
+            TeamGames::<T>::insert(&move_hash, team_size);
+            TeamMembers::<T>::insert(&move_hash, &owner, Team::First);
+
+            Self::deposit_event(Event::<T>::TeamGameStarted { game_id: move_hash, team_size });
+            Self::deposit_event(Event::<T>::TeamJoined { game_id: move_hash, player: owner, team: Team::First });
+            Ok(())
+        }
+
+        /// Place a bet on an existing team game and join the given team. The game must be in
+        /// "betting" stage and the team must not be full.
+        #[pallet::weight(T::WeightInfo::join_team())]
+        pub fn join_team(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            team: Team,
+            move_hash: T::MoveHash,
+        ) -> DispatchResult {
+            let joiner = ensure_signed(origin)?;
+            let team_size = TeamGames::<T>::get(&game_id).ok_or(Error::<T>::NotTeamGame)?;
+            ensure!(Self::has_team_seat(&game_id, team, team_size), Error::<T>::TeamFull);
+            Self::do_join(joiner.clone(), game_id, move_hash)?;
+            TeamMembers::<T>::insert(&game_id, &joiner, team);
+
+            Self::deposit_event(Event::<T>::TeamJoined { game_id, player: joiner, team });
+            Ok(())
+        }
+
+        /// Stake on a player of a game winning without playing. The game must be in "betting"
+        /// stage. A spectator can place a single side-bet per game in the native currency, a game
+        /// takes up to `MaxSideBets` side-bets.
+        #[pallet::weight(T::WeightInfo::side_bet())]
+        pub fn side_bet(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            player: T::AccountId,
+            #[pallet::compact] stake: BalanceOf<T>,
+        ) -> DispatchResult {
+            let spectator = ensure_signed(origin)?;
+            ensure!(!Paused::<T>::get(), Error::<T>::GamesPaused);
+            let game = Games::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
+            ensure!(Self::can_join_game(&game), Error::<T>::GameWrongStage);
+            ensure!(!Self::can_make_move(&game_id, &player), Error::<T>::PlayerMoveMissing);
+            ensure!(Self::can_make_move(&game_id, &spectator), Error::<T>::SideBetConflict);
+            ensure!(!SideBets::<T>::contains_key(&game_id, &spectator), Error::<T>::SideBetPlaced);
+            ensure!(SideBetCounts::<T>::get(&game_id) < T::MaxSideBets::get(), Error::<T>::TooManySideBets);
+            ensure!(!stake.is_zero(), Error::<T>::BetInvalid);
+            ensure!(!Self::is_self_excluded(&spectator), Error::<T>::PlayerSelfExcluded);
+            ensure!(Self::within_spending_cap(&spectator, stake), Error::<T>::SpendingCapExceeded);
+
+            T::Currency::transfer(&spectator, &Self::account_id(), stake, ExistenceRequirement::KeepAlive)
+                .map_err(|_| Error::<T>::MoneyNotEnough)?;
+            Escrow::<T>::mutate(|escrow| *escrow = escrow.saturating_add(stake));
+            Self::record_spending(&spectator, stake);
+            SideBets::<T>::insert(&game_id, &spectator, (player.clone(), stake));
+            SideBetCounts::<T>::mutate(&game_id, |count| *count = count.saturating_add(1));
+
+            Self::deposit_event(Event::<T>::SideBetPlaced { game_id, spectator, player, stake });
+            Ok(())
+        }
+
+        /// Reveal a move. The game must be in "revealing" stage. A player needs to provide a game id
+        /// and a move reveal. The move reveal will be hashed and compared with the move hash.
+        /// The first byte of the reveal is the move itself. The rest of the reveal is the salt.
+        /// The actual move should be one of the following: 0 - Rock, 1 - Paper, 2 - Scissors.
+        ///
+        /// The weight of ending a game of `MaxPlayers` players and `MaxSideBets` side-bets is
+        /// charged up front and the difference to the actual numbers is refunded.
+        #[pallet::weight(
+            T::WeightInfo::reveal_move(move_reveal.len() as u32, MaxPlayers::get())
+                .saturating_add(T::OnGameEnded::weight(MaxPlayers::get()))
+                .saturating_add(T::WeightInfo::settle_side_bets(T::MaxSideBets::get()))
+        )]
+        pub fn reveal(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            move_reveal: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let player = ensure_signed(origin)?;
+            let reveal_len = move_reveal.len() as u32;
+            let (players, side_bets) = Self::do_reveal(player, game_id, move_reveal)?;
+            Ok(Some(
+                T::WeightInfo::reveal_move(reveal_len, players)
+                    .saturating_add(T::OnGameEnded::weight(players))
+                    .saturating_add(T::WeightInfo::settle_side_bets(side_bets))
+            ).into())
+        }
+
+        /// Reveal a move without paying a fee. The `reveal_signing_payload` of the payload must be
+        /// signed by the player and its nonce must match the number of the unsigned reveals of the
+        /// player so far. The transaction is validated by `ValidateUnsigned` before entering the
+        /// transaction pool. The weight is charged and refunded like the one of `reveal`.
+        #[pallet::weight(
+            T::WeightInfo::reveal_unsigned(payload.move_reveal.len() as u32, MaxPlayers::get())
+                .saturating_add(T::OnGameEnded::weight(MaxPlayers::get()))
+                .saturating_add(T::WeightInfo::settle_side_bets(T::MaxSideBets::get()))
+        )]
+        pub fn reveal_unsigned(
+            origin: OriginFor<T>,
+            payload: RevealPayloadOf<T>,
+            _signature: T::Signature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+            let RevealPayload { player, game_id, move_reveal, nonce } = payload;
+            ensure!(nonce == RevealNonces::<T>::get(&player), Error::<T>::RevealNonceInvalid);
+
+            RevealNonces::<T>::insert(&player, nonce.wrapping_add(1));
+            let reveal_len = move_reveal.len() as u32;
+            let (players, side_bets) = Self::do_reveal(player, game_id, move_reveal)?;
+            Ok(Some(
+                T::WeightInfo::reveal_unsigned(reveal_len, players)
+                    .saturating_add(T::OnGameEnded::weight(players))
+                    .saturating_add(T::WeightInfo::settle_side_bets(side_bets))
+            ).into())
+        }
+
+        /// Update the game parameters. The new parameters apply to the games started or joined
+        /// afterwards. Can only be called by the `AdminOrigin`.
+        #[pallet::weight(T::WeightInfo::set_config())]
+        pub fn set_config(origin: OriginFor<T>, config: GameConfigOf<T>) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(config.is_valid(T::JackpotShare::get()), Error::<T>::ConfigInvalid);
+
+            PalletConfig::<T>::put(&config);
+
+            Self::deposit_event(Event::<T>::ConfigUpdated { config });
+            Ok(())
+        }
+
+        /// Pause starting and joining games. The games in progress can still be revealed and
+        /// ended. Can only be called by the `AdminOrigin`.
+        #[pallet::weight(T::WeightInfo::pause())]
+        pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::put(true);
+
+            Self::deposit_event(Event::<T>::Paused);
+            Ok(())
+        }
+
+        /// Allow starting and joining games again. Can only be called by the `AdminOrigin`.
+        #[pallet::weight(T::WeightInfo::unpause())]
+        pub fn unpause(origin: OriginFor<T>) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::kill();
+
+            Self::deposit_event(Event::<T>::Unpaused);
+            Ok(())
+        }
+
+        /// End a game at any stage and settle it as if its round was over. Can only be called by
+        /// the `AdminOrigin`. A tie of a game with a random tie-break is split as there is no
+        /// randomness unknown to the players yet.
+        ///
+        /// The weight of a game of `MaxPlayers` players and `MaxSideBets` side-bets is charged up
+        /// front and the difference to the actual numbers is refunded.
+        #[pallet::weight(
+            T::WeightInfo::force_end(MaxPlayers::get())
+                .saturating_add(T::OnGameEnded::weight(MaxPlayers::get()))
+                .saturating_add(T::WeightInfo::settle_side_bets(T::MaxSideBets::get()))
+        )]
+        pub fn force_end(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let game = Games::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
+            let players = Moves::<T>::iter_prefix(&game_id).count() as u32;
+            let side_bets = SideBetCounts::<T>::get(&game_id);
+
+            Self::deposit_event(Event::<T>::ForceEnded { game_id });
+            Self::remove_from_index(&game_id, &game);
+            Self::end_game(&game_id, None);
+            Ok(Some(
+                T::WeightInfo::force_end(players)
+                    .saturating_add(T::OnGameEnded::weight(players))
+                    .saturating_add(T::WeightInfo::settle_side_bets(side_bets))
+            ).into())
+        }
+
+        /// Cancel a game at any stage and return the bets to all its players. Can only be called
+        /// by the `AdminOrigin`.
+        ///
+        /// The weight of a game of `MaxPlayers` players and `MaxSideBets` side-bets is charged up
+        /// front and the difference to the actual numbers is refunded.
+        #[pallet::weight(
+            T::WeightInfo::force_refund(MaxPlayers::get())
+                .saturating_add(T::WeightInfo::settle_side_bets(T::MaxSideBets::get()))
+        )]
+        pub fn force_refund(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResultWithPostInfo {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let game = Games::<T>::take(&game_id).ok_or(Error::<T>::GameMissing)?;
+            let side_bets = SideBetCounts::<T>::get(&game_id);
+
+            Self::remove_from_index(&game_id, &game);
+            let players = Self::refund_game(&game_id, &game);
+
+            let weight = T::WeightInfo::force_refund(players.len() as u32)
+                .saturating_add(T::WeightInfo::settle_side_bets(side_bets));
+            Self::deposit_event(Event::<T>::ForceRefunded { game_id, players, amount: game.bet });
+            Ok(Some(weight).into())
+        }
+
+        /// Change how the winner of a game is chosen if several players tie. Can only be called by
+        /// the owner of a game which is not a team game before anybody joins it.
+        #[pallet::weight(T::WeightInfo::set_tie_break())]
+        pub fn set_tie_break(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            tie_break: TieBreak,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            Self::ensure_can_configure(&owner, &game_id)?;
+            ensure!(!TeamGames::<T>::contains_key(&game_id), Error::<T>::TieBreakUnavailable);
+
+            match tie_break {
+                TieBreak::Split => GameTieBreaks::<T>::remove(&game_id),
+                _ => GameTieBreaks::<T>::insert(&game_id, tie_break),
+            }
+            Self::deposit_event(Event::<T>::TieBreakSet { game_id, tie_break });
+            Ok(())
+        }
+
+        /// Roll the whole pot of a game over to the jackpot if nobody wins instead of refunding the
+        /// bets. Can only be called by the owner of a native currency game without a prize before
+        /// anybody joins it.
+        #[pallet::weight(T::WeightInfo::set_jackpot_rollover())]
+        pub fn set_jackpot_rollover(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            rollover: bool,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            Self::ensure_can_configure(&owner, &game_id)?;
+            ensure!(
+                !GameAssets::<T>::contains_key(&game_id) && !GamePrizes::<T>::contains_key(&game_id),
+                Error::<T>::JackpotUnavailable
+            );
+
+            if rollover {
+                JackpotRollovers::<T>::insert(&game_id, ());
+            } else {
+                JackpotRollovers::<T>::remove(&game_id);
+            }
+            Self::deposit_event(Event::<T>::JackpotRolloverSet { game_id, rollover });
+            Ok(())
+        }
+
+        /// Start a game against the house. A player needs to provide a bet amount and a move hash
+        /// which becomes the game id. The bet is transferred to the house account which must be
+        /// able to cover the winnings.
+        #[pallet::weight(T::WeightInfo::play_house())]
+        pub fn play_house(
+            origin: OriginFor<T>,
+            #[pallet::compact] bet: BalanceOf<T>,
+            move_hash: T::MoveHash,
+        ) -> DispatchResult {
+            let player = ensure_signed(origin)?;
+            ensure!(!Paused::<T>::get(), Error::<T>::GamesPaused);
+            ensure!(Self::valid_bet(&PalletConfig::<T>::get(), bet), Error::<T>::BetInvalid);
+            ensure!(Self::can_create_game(&move_hash), Error::<T>::GameExists);
+            Self::ensure_can_play(&player, None, bet)?;
+
+            let house = Self::house_account();
+            let liability = HouseLiability::<T>::get().saturating_add(Self::house_win_payout(bet));
+            ensure!(
+                T::Currency::free_balance(&house).saturating_add(bet) >= liability,
+                Error::<T>::HouseBankrollLow
+            );
+            T::Currency::transfer(&player, &house, bet, ExistenceRequirement::KeepAlive)
+                .map_err(|_| Error::<T>::MoneyNotEnough)?;
+            HouseLiability::<T>::put(liability);
+            Self::enter_game(&player);
+            Self::record_spending(&player, bet);
+
+            let now = Self::now();
+            let draw_at = now.saturating_add(T::HouseDrawDelay::get().max(1).into());
+            HouseGames::<T>::insert(move_hash, HouseGame {
+                player: player.clone(),
+                bet,
+                played_at: now,
+                deadline: draw_at,
+                house_hand: None,
+            });
+            HouseGamesIndex::<T>::append(draw_at, move_hash);
+
+            Self::deposit_event(Event::<T>::HouseGameStarted { game_id: move_hash, player, bet, draw_at });
+            Ok(())
+        }
+
+        /// Reveal a move of a game against the house once the house hand is drawn. The reveal
+        /// format is the same as of the `reveal` call.
+        #[pallet::weight(T::WeightInfo::reveal_house())]
+        pub fn reveal_house(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            move_reveal: Vec<u8>,
+        ) -> DispatchResult {
+            let player = ensure_signed(origin)?;
+            let game = HouseGames::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
+            ensure!(game.player == player, Error::<T>::PlayerMoveMissing);
+            let house_hand = game.house_hand.ok_or(Error::<T>::HouseHandNotReady)?;
+            ensure!(Self::reveal_match(&move_reveal, &game_id), Error::<T>::PlayerRevealMismatch);
+            let hand = move_reveal.first()
+                .and_then(|&value| Hand::new(value))
+                .ok_or(Error::<T>::PlayerMoveInvalid)?;
+
+            HouseGamesIndex::<T>::mutate_exists(game.deadline, |maybe_game_ids| {
+                if let Some(game_ids) = maybe_game_ids {
+                    game_ids.retain(|id| *id != game_id);
+                    if game_ids.is_empty() {
+                        *maybe_game_ids = None;
+                    }
+                }
+            });
+            Self::end_house_game(&game_id, Some(hand), house_hand);
+            Ok(())
+        }
+
+        /// Commit a move to the rematch of a recently ended game. Can only be called by the players
+        /// of the game. The rematch starts in "revealing" stage once all of them commit.
+        #[pallet::weight(T::WeightInfo::rematch())]
+        pub fn rematch(origin: OriginFor<T>, game_id: GameId<T>, move_hash: T::MoveHash) -> DispatchResult {
+            let player = ensure_signed(origin)?;
+            ensure!(!Paused::<T>::get(), Error::<T>::GamesPaused);
+            let mut rematch = match Rematches::<T>::get(&game_id) {
+                Some(rematch) => rematch,
+                None => Self::new_rematch(&game_id)?,
+            };
+            ensure!(rematch.players.contains(&player), Error::<T>::NotGamePlayer);
+            ensure!(rematch.moves.iter().all(|(committed, _)| *committed != player), Error::<T>::PlayerMoveMade);
+            Self::ensure_can_play(&player, None, rematch.bet)?;
+
+            Self::deposit_bet(&player, None, rematch.bet)?;
+            if rematch.moves.is_empty() {
+                RematchesIndex::<T>::append(rematch.expires_at, game_id);
+                ReservedGameIds::<T>::insert(Self::rematch_id(&game_id), ());
+            }
+            rematch.moves.push((player.clone(), move_hash));
+            Self::deposit_event(Event::<T>::RematchCommitted { game_id, player });
+
+            if rematch.moves.len() == rematch.players.len() {
+                Rematches::<T>::remove(&game_id);
+                Self::start_rematch(&game_id, rematch);
+            } else {
+                Rematches::<T>::insert(&game_id, rematch);
+            }
+            Ok(())
+        }
+
+        /// Schedule games to open at `opens_at` and then every `period` blocks for `repeats` more
+        /// times. The games are opened without players with the given round length and bet.
+        ///
+        /// The `ScheduleDeposit` is reserved from the owner and every opened game takes an equal
+        /// share of it. The share is slashed if nobody joins the game and released when the game
+        /// ends otherwise, the rest is returned when the schedule completes or is cancelled.
+        #[pallet::weight(T::WeightInfo::schedule_game())]
+        pub fn schedule_game(
+            origin: OriginFor<T>,
+            #[pallet::compact] round_length: T::BlockNumber,
+            #[pallet::compact] bet: BalanceOf<T>,
+            opens_at: T::BlockNumber,
+            period: Option<T::BlockNumber>,
+            repeats: u32,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let config = PalletConfig::<T>::get();
+            ensure!(Self::valid_round_length(&config, round_length), Error::<T>::RoundLengthInvalid);
+            ensure!(Self::valid_bet(&config, bet), Error::<T>::BetInvalid);
+            ensure!(
+                Self::valid_schedule(opens_at, period, repeats),
+                Error::<T>::ScheduleInvalid
+            );
+            ensure!(Self::has_schedule_room(opens_at), Error::<T>::ScheduleBlockFull);
+            let schedule_id = NextScheduleId::<T>::get();
+            let game_id = Self::scheduled_game_id(schedule_id, opens_at);
+            ensure!(Self::can_create_game(&game_id), Error::<T>::GameExists);
+
+            let deposit = T::ScheduleDeposit::get();
+            T::Currency::reserve(&owner, deposit).map_err(|_| Error::<T>::MoneyNotEnough)?;
+            NextScheduleId::<T>::put(schedule_id.wrapping_add(1));
+            Schedules::<T>::insert(schedule_id, GameSchedule {
+                owner: owner.clone(),
+                round_length,
+                bet,
+                period,
+                repeats,
+                opens_at,
+                deposit,
+            });
+            SchedulesIndex::<T>::append(opens_at, schedule_id);
+            ReservedGameIds::<T>::insert(game_id, ());
+
+            Self::deposit_event(Event::<T>::GameScheduled { schedule_id, owner, opens_at });
+            Ok(())
+        }
+
+        /// Cancel a schedule so it opens no more games and return the deposit. Can only be called
+        /// by the owner of the schedule, the games already opened are not affected.
+        #[pallet::weight(T::WeightInfo::cancel_schedule())]
+        pub fn cancel_schedule(origin: OriginFor<T>, schedule_id: ScheduleId) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+            let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleMissing)?;
+            ensure!(schedule.owner == owner, Error::<T>::NotGameOwner);
+
+            Schedules::<T>::remove(schedule_id);
+            SchedulesIndex::<T>::mutate_exists(schedule.opens_at, |maybe_schedule_ids| {
+                if let Some(schedule_ids) = maybe_schedule_ids {
+                    schedule_ids.retain(|id| *id != schedule_id);
+                    if schedule_ids.is_empty() {
+                        *maybe_schedule_ids = None;
+                    }
+                }
+            });
+            ReservedGameIds::<T>::remove(Self::scheduled_game_id(schedule_id, schedule.opens_at));
+            T::Currency::unreserve(&owner, schedule.deposit);
+            Self::deposit_event(Event::<T>::ScheduleCancelled { schedule_id });
+            Ok(())
+        }
+
+        /// Post a challenge of the correspondence mode. A challenger needs to provide a stake and a
+        /// move hash which becomes the game id. The challenge stays open until it is answered or
+        /// cancelled.
+        #[pallet::weight(T::WeightInfo::post_challenge())]
+        pub fn post_challenge(
+            origin: OriginFor<T>,
+            #[pallet::compact] stake: BalanceOf<T>,
+            move_hash: T::MoveHash,
+        ) -> DispatchResult {
+            let challenger = ensure_signed(origin)?;
+            ensure!(!Paused::<T>::get(), Error::<T>::GamesPaused);
+            ensure!(Self::valid_bet(&PalletConfig::<T>::get(), stake), Error::<T>::BetInvalid);
             ensure!(Self::can_create_game(&move_hash), Error::<T>::GameExists);
+            Self::ensure_can_play(&challenger, None, stake)?;
+
+            Self::deposit_bet(&challenger, None, stake)?;
+            Challenges::<T>::insert(move_hash, Challenge {
+                challenger: challenger.clone(),
+                stake,
+                response: None,
+                deadline: None,
+                cancel_at: None,
+            });
+
+            Self::deposit_event(Event::<T>::ChallengePosted { game_id: move_hash, challenger, stake });
+            Ok(())
+        }
+
+        /// Cancel an unanswered challenge. The challenge can still be answered for
+        /// `ChallengeCancelDelay` blocks, the stake is returned afterwards if it is not. Can only
+        /// be called by the challenger.
+        #[pallet::weight(T::WeightInfo::cancel_challenge())]
+        pub fn cancel_challenge(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let challenger = ensure_signed(origin)?;
+            let challenge = Challenges::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
+            ensure!(challenge.challenger == challenger, Error::<T>::NotGameOwner);
+            ensure!(challenge.response.is_none(), Error::<T>::ChallengeAnswered);
+            ensure!(challenge.cancel_at.is_none(), Error::<T>::ChallengeCancelling);
+
+            let cancel_at = Self::now().saturating_add(T::ChallengeCancelDelay::get().max(1).into());
+            Challenges::<T>::insert(&game_id, Challenge { cancel_at: Some(cancel_at), ..challenge });
+            ChallengesIndex::<T>::append(cancel_at, game_id);
+
+            Self::deposit_event(Event::<T>::ChallengeCancelRequested { game_id, cancel_at });
+            Ok(())
+        }
+
+        /// Answer a challenge with a plaintext hand and the stake of the challenge. The challenger
+        /// then has `ChallengeRevealLength` blocks to reveal the move. A challenge whose
+        /// cancellation is requested can be answered until it is cancelled.
+        #[pallet::weight(T::WeightInfo::respond_challenge())]
+        pub fn respond_challenge(origin: OriginFor<T>, game_id: GameId<T>, hand: Hand) -> DispatchResult {
+            let responder = ensure_signed(origin)?;
+            ensure!(!Paused::<T>::get(), Error::<T>::GamesPaused);
+            let challenge = Challenges::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
+            ensure!(challenge.response.is_none(), Error::<T>::ChallengeAnswered);
+            ensure!(challenge.challenger != responder, Error::<T>::ChallengeOwnAnswer);
+            Self::ensure_can_play(&responder, None, challenge.stake)?;
+
+            Self::deposit_bet(&responder, None, challenge.stake)?;
+            let deadline = Self::now().saturating_add(T::ChallengeRevealLength::get().max(1).into());
+            if let Some(cancel_at) = challenge.cancel_at {
+                Self::remove_from_challenges_index(cancel_at, &game_id);
+            }
+            Challenges::<T>::insert(&game_id, Challenge {
+                response: Some((responder.clone(), hand)),
+                deadline: Some(deadline),
+                cancel_at: None,
+                ..challenge
+            });
+            ChallengesIndex::<T>::append(deadline, game_id);
+
+            Self::deposit_event(Event::<T>::ChallengeAnswered { game_id, responder, hand, deadline });
+            Ok(())
+        }
+
+        /// Reveal the move of an answered challenge. The reveal format is the same as of the
+        /// `reveal` call.
+        #[pallet::weight(T::WeightInfo::reveal_challenge())]
+        pub fn reveal_challenge(
+            origin: OriginFor<T>,
+            game_id: GameId<T>,
+            move_reveal: Vec<u8>,
+        ) -> DispatchResult {
+            let challenger = ensure_signed(origin)?;
+            let challenge = Challenges::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
+            ensure!(challenge.challenger == challenger, Error::<T>::PlayerMoveMissing);
+            let deadline = challenge.deadline.ok_or(Error::<T>::ChallengeNotAnswered)?;
+            ensure!(Self::reveal_match(&move_reveal, &game_id), Error::<T>::PlayerRevealMismatch);
+            let hand = move_reveal.first()
+                .and_then(|&value| Hand::new(value))
+                .ok_or(Error::<T>::PlayerMoveInvalid)?;
+
+            Self::remove_from_challenges_index(deadline, &game_id);
+            Self::end_challenge(&game_id, Some(hand));
+            Ok(())
+        }
+
+        /// Exclude the caller from starting and joining games until the given block. The games
+        /// already joined can still be revealed. An exclusion can be extended but not shortened.
+        #[pallet::weight(T::WeightInfo::self_exclude())]
+        pub fn self_exclude(origin: OriginFor<T>, until: T::BlockNumber) -> DispatchResult {
+            let player = ensure_signed(origin)?;
+            ensure!(until > Self::now(), Error::<T>::SelfExclusionTooShort);
+            ensure!(
+                SelfExclusions::<T>::get(&player).map_or(true, |current| until >= current),
+                Error::<T>::SelfExclusionTooShort
+            );
+
+            SelfExclusions::<T>::insert(&player, until);
+
+            Self::deposit_event(Event::<T>::SelfExcluded { player, until });
+            Ok(())
+        }
+
+        /// Set or remove the maximal amount the caller may bet per `SpendingPeriod`. Only the
+        /// bets placed while a cap is set count towards it.
+        ///
+        /// A lower cap applies immediately. A higher cap or the removal of the cap only applies
+        /// from the next spending period. The cap is in the native currency and does not limit the
+        /// bets of the asset games.
+        #[pallet::weight(T::WeightInfo::set_spending_cap())]
+        pub fn set_spending_cap(origin: OriginFor<T>, cap: Option<BalanceOf<T>>) -> DispatchResult {
+            let player = ensure_signed(origin)?;
+            let current = Self::spending_cap(&player);
+            PendingSpendingCaps::<T>::remove(&player);
+
+            let lowers = match (cap, current) {
+                (Some(cap), Some(current)) => cap <= current,
+                (Some(_), None) => true,
+                (None, _) => current.is_none(),
+            };
+            if lowers {
+                SpendingCaps::<T>::set(&player, cap);
+                Self::deposit_event(Event::<T>::SpendingCapSet { player, cap });
+            } else {
+                SpendingCaps::<T>::set(&player, current);
+                let period = Self::spending_period().saturating_add(One::one());
+                PendingSpendingCaps::<T>::insert(&player, (period, cap));
+
+                let applies_at = period.saturating_mul(T::SpendingPeriod::get().max(1).into());
+                Self::deposit_event(Event::<T>::SpendingCapPending { player, cap, applies_at });
+            }
+            Ok(())
+        }
+
+        /// Claim the payout in the given asset, `None` for the native currency, which could not be
+        /// transferred to the caller when the game ended.
+        #[pallet::weight(T::WeightInfo::claim_payout())]
+        pub fn claim_payout(origin: OriginFor<T>, asset: Option<AssetIdOf<T>>) -> DispatchResult {
+            let player = ensure_signed(origin)?;
+            let amount = UnclaimedPayouts::<T>::take(&player, asset);
+            ensure!(!amount.is_zero(), Error::<T>::PayoutMissing);
+
+            Self::transfer_out(&player, asset, amount)?;
+
+            Self::deposit_event(Event::<T>::PayoutClaimed { player, asset, amount });
+            Ok(())
+        }
+
+        /// Transfer all the rake collected from the games played for the asset to the given
+        /// account. Can only be called by the `AdminOrigin`.
+        #[pallet::weight(T::WeightInfo::withdraw_asset_rake())]
+        pub fn withdraw_asset_rake(origin: OriginFor<T>, asset: AssetIdOf<T>, dest: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let amount = AssetRakes::<T>::take(asset);
+            ensure!(!amount.is_zero(), Error::<T>::RakeMissing);
+
+            Self::transfer_out(&dest, Some(asset), amount)?;
+
+            Self::deposit_event(Event::<T>::AssetRakeWithdrawn { asset, dest, amount });
+            Ok(())
+        }
+
+        /// Claim the prize item of an ended game which could not be transferred to the caller when
+        /// the game ended.
+        #[pallet::weight(T::WeightInfo::claim_prize())]
+        pub fn claim_prize(origin: OriginFor<T>, game_id: GameId<T>) -> DispatchResult {
+            let player = ensure_signed(origin)?;
+            ensure!(!Games::<T>::contains_key(&game_id), Error::<T>::PrizeMissing);
+            let (_, collection, item) = GamePrizes::<T>::get(&game_id)
+                .filter(|(recipient, _, _)| *recipient == player)
+                .ok_or(Error::<T>::PrizeMissing)?;
+
+            <T::Items as nonfungibles::Transfer<_>>::transfer(&collection, &item, &player)?;
+            GamePrizes::<T>::remove(&game_id);
+
+            Self::deposit_event(Event::<T>::PrizeClaimed { game_id, player, collection, item });
+            Ok(())
+        }
+
+        /// Transfer the amount from the caller to the bankroll of the house.
+        #[pallet::weight(T::WeightInfo::fund_house())]
+        pub fn fund_house(origin: OriginFor<T>, #[pallet::compact] amount: BalanceOf<T>) -> DispatchResult {
+            let source = ensure_signed(origin)?;
+            T::Currency::transfer(&source, &Self::house_account(), amount, ExistenceRequirement::KeepAlive)?;
+
+            Self::deposit_event(Event::<T>::HouseFunded { source, amount });
+            Ok(())
+        }
+
+        /// Transfer the amount from the bankroll of the house to the given account. The house must
+        /// keep enough to cover the `HouseLiability`. Can only be called by the `AdminOrigin`.
+        #[pallet::weight(T::WeightInfo::withdraw_house())]
+        pub fn withdraw_house(
+            origin: OriginFor<T>,
+            dest: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let house = Self::house_account();
+            ensure!(
+                T::Currency::free_balance(&house).saturating_sub(amount) >= HouseLiability::<T>::get(),
+                Error::<T>::HouseBankrollLow
+            );
+            T::Currency::transfer(&house, &dest, amount, ExistenceRequirement::KeepAlive)?;
+
+            Self::deposit_event(Event::<T>::HouseWithdrawn { dest, amount });
+            Ok(())
+        }
+
+        /// Claim the winnings of the games against the house which could not be transferred to the
+        /// caller when the games ended.
+        #[pallet::weight(T::WeightInfo::claim_house_payout())]
+        pub fn claim_house_payout(origin: OriginFor<T>) -> DispatchResult {
+            let player = ensure_signed(origin)?;
+            let amount = HouseDebts::<T>::take(&player);
+            ensure!(!amount.is_zero(), Error::<T>::HouseDebtMissing);
+
+            T::Currency::transfer(&Self::house_account(), &player, amount, ExistenceRequirement::KeepAlive)?;
+            HouseLiability::<T>::mutate(|liability| *liability = liability.saturating_sub(amount));
+
+            Self::deposit_event(Event::<T>::HouseDebtPaid { player, amount });
+            Ok(())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::reveal_unsigned { payload, signature } => Self::validate_reveal(payload, signature),
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    fn now() -> T::BlockNumber {
+        frame_system::Pallet::<T>::block_number()
+    }
+
+    /// The account holding the bets of the asset games.
+    pub fn account_id() -> T::AccountId {
+        T::PalletId::get().into_account_truncating()
+    }
+
+    /// Reveals the move of the player and ends the game if it is the last reveal. Returns the
+    /// number of the players and the side-bets of the ended game, zeros if the game goes on.
+    fn do_reveal(
+        player: T::AccountId,
+        game_id: GameId<T>,
+        move_reveal: Vec<u8>,
+    ) -> Result<(u32, u32), DispatchError> {
+        let game = Games::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
+        ensure!(Self::can_reveal_move(&game), Error::<T>::GameWrongStage);
+
+        let is_last = Self::try_reveal_move(&game_id, &player, &move_reveal)?;
+        Self::deposit_event(Event::<T>::MoveRevealed { game_id, player });
+
+        if !is_last {
+            return Ok((0, 0));
+        }
+        let players = Moves::<T>::iter_prefix(&game_id).count() as u32;
+        let side_bets = SideBetCounts::<T>::get(&game_id);
+        Self::end_game(&game_id, None);
+        Ok((players, side_bets))
+    }
+
+    /// The message a player signs to reveal with `reveal_signing_payload`: the payload prefixed by
+    /// the pallet domain and the genesis hash, so the signature is not valid for any other message
+    /// or chain.
+    pub fn reveal_signing_payload(payload: &RevealPayloadOf<T>) -> Vec<u8> {
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero());
+        (b"ropasci/reveal", genesis_hash, payload).encode()
+    }
+
+    /// Validates an unsigned reveal: the payload must be signed by the player, the game must be in
+    /// "revealing" stage and the reveal must match the unrevealed move of the player. The reveal is
+    /// valid until the deadline and the more urgent it is the higher its priority. A reveal with a
+    /// future nonce requires the reveal of the previous nonce of the player.
+    fn validate_reveal(payload: &RevealPayloadOf<T>, signature: &T::Signature) -> TransactionValidity {
+        let RevealPayload { player, game_id, move_reveal, nonce } = payload;
+        if !signature.verify(&Self::reveal_signing_payload(payload)[..], player) {
+            return InvalidTransaction::BadProof.into();
+        }
+
+        let next_nonce = RevealNonces::<T>::get(player);
+        if *nonce < next_nonce {
+            return InvalidTransaction::Stale.into();
+        }
+
+        let game = match Games::<T>::get(game_id) {
+            Some(game) if Self::can_reveal_move(&game) => game,
+            Some(_) => return InvalidTransaction::Future.into(),
+            None => return InvalidTransaction::Stale.into(),
+        };
+        match Moves::<T>::get(game_id, player) {
+            Some(Move { hand: Some(_), .. }) => return InvalidTransaction::Stale.into(),
+            Some(Move { hash, hand: None }) if Self::reveal_match(move_reveal, &hash) => {}
+            _ => return InvalidTransaction::BadProof.into(),
+        }
+
+        let remaining: u64 = Self::reveal_deadline(&game).saturating_sub(Self::now()).saturated_into();
+        let mut validity = ValidTransaction::with_tag_prefix("RoPaSciReveal")
+            .priority(T::UnsignedRevealPriority::get().saturating_sub(remaining))
+            .and_provides((player, nonce))
+            .and_provides((game_id, player))
+            .longevity(remaining.max(1))
+            .propagate(true);
+        if *nonce > next_nonce {
+            validity = validity.and_requires((player, nonce - 1));
+        }
+        validity.build()
+    }
+
+    fn do_start(
+        owner: T::AccountId,
+        round_length: T::BlockNumber,
+        bet: BalanceOf<T>,
+        asset: Option<AssetIdOf<T>>,
+        prize: Option<(CollectionIdOf<T>, ItemIdOf<T>)>,
+        move_hash: T::MoveHash,
+    ) -> DispatchResult {
+        ensure!(!Paused::<T>::get(), Error::<T>::GamesPaused);
+        let config = PalletConfig::<T>::get();
+        ensure!(Self::valid_round_length(&config, round_length), Error::<T>::RoundLengthInvalid);
+        ensure!(Self::valid_asset_bet(&config, asset, bet), Error::<T>::BetInvalid);
+        ensure!(Self::can_create_game(&move_hash), Error::<T>::GameExists);
+        Self::ensure_can_play(&owner, asset, bet)?;
+
+        match prize {
+            None => Self::deposit_bet(&owner, asset, bet)?,
+            Some((collection, item)) => Self::escrow_prize(&owner, &move_hash, collection, item)?,
+        }
+        let deposit = Self::reserve_deposit(&owner, &move_hash)?;
+        Self::start_game(&owner, move_hash, round_length, bet);
+        if let Some(asset) = asset {
+            GameAssets::<T>::insert(move_hash, asset);
+        }
+
+        Self::deposit_event(Event::<T>::GameStarted {
+            game_id: move_hash,
+            owner,
+            round_length,
+            bet,
+            asset,
+            deposit,
+            schedule: None,
+        });
+        Ok(())
+    }
+
+    fn do_join(joiner: T::AccountId, game_id: GameId<T>, move_hash: T::MoveHash) -> DispatchResult {
+        ensure!(!Paused::<T>::get(), Error::<T>::GamesPaused);
+        let game = Games::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
+        ensure!(Self::can_join_game(&game), Error::<T>::GameWrongStage);
+        ensure!(Self::has_free_seat(&PalletConfig::<T>::get(), &game), Error::<T>::GameFull);
+        ensure!(Self::can_make_move(&game_id, &joiner), Error::<T>::PlayerMoveMade);
+        ensure!(!SideBets::<T>::contains_key(&game_id, &joiner), Error::<T>::SideBetConflict);
+        let asset = GameAssets::<T>::get(&game_id);
+        Self::ensure_can_play(&joiner, asset, game.bet)?;
+
+        Self::deposit_bet(&joiner, asset, game.bet)?;
+        Self::join_game(&game_id, &joiner, move_hash);
+
+        Self::deposit_event(Event::<T>::BetPlaced { game_id, player: joiner });
+        Ok(())
+    }
+
+    fn escrow_prize(
+        owner: &T::AccountId,
+        game_id: &GameId<T>,
+        collection: CollectionIdOf<T>,
+        item: ItemIdOf<T>,
+    ) -> DispatchResult {
+        ensure!(
+            <T::Items as nonfungibles::Inspect<_>>::owner(&collection, &item).as_ref() == Some(owner),
+            Error::<T>::PrizeNotOwned
+        );
+        <T::Items as nonfungibles::Transfer<_>>::transfer(&collection, &item, &Self::account_id())?;
+
+        Self::enter_game(owner);
+        GamePrizes::<T>::insert(game_id, (owner, collection, item));
+        Self::deposit_event(Event::<T>::PrizeEscrowed { game_id: *game_id, collection, item });
+        Ok(())
+    }
+
+    fn is_prize_owner(prize: &Option<PrizeOf<T>>, player: &T::AccountId) -> bool {
+        matches!(prize, Some((owner, _, _)) if owner == player)
+    }
+
+    /// The winner receiving the prize item, the one with the lowest hash of the game id and the
+    /// account if there are several.
+    fn prize_winner(game_id: &GameId<T>, winners: &[T::AccountId]) -> Option<T::AccountId> {
+        winners.iter()
+            .min_by_key(|winner| T::MoveHasher::hash_of(&(game_id, winner)))
+            .cloned()
+    }
+
+    /// Ensures the account owns the game and nobody joined it yet, so the game can be configured.
+    fn ensure_can_configure(owner: &T::AccountId, game_id: &GameId<T>) -> Result<(), Error<T>> {
+        let game = Games::<T>::get(game_id).ok_or(Error::<T>::GameMissing)?;
+        ensure!(
+            GameDeposits::<T>::get(game_id).map_or(false, |(game_owner, _)| game_owner == *owner),
+            Error::<T>::NotGameOwner
+        );
+        ensure!(
+            game.stage == GameStage::Betting { participating_players: 1 },
+            Error::<T>::GameAlreadyJoined
+        );
+        Ok(())
+    }
+
+    /// Draws a single winner of the tied ones if the game has a random tie-break.
+    fn break_tie(
+        game_id: &GameId<T>,
+        winners: Vec<T::AccountId>,
+        seed: Option<T::Hash>,
+    ) -> Vec<T::AccountId> {
+        match (GameTieBreaks::<T>::take(game_id), seed) {
+            (Some(TieBreak::Random), Some(seed)) if winners.len() > 1 => {
+                let index = u64::decode(&mut seed.as_ref()).unwrap_or_default() % winners.len() as u64;
+                let winner = winners[index as usize].clone();
 
-            Self::deposit_bet(&owner, bet)?;
-            Self::start_game(&owner, move_hash, round_length, bet);
+                Self::deposit_event(Event::<T>::TieBroken {
+                    game_id: *game_id,
+                    seed,
+                    tied: winners,
+                    winner: winner.clone(),
+                });
+                vec![winner]
+            }
+            _ => winners,
+        }
+    }
 
-            Self::deposit_event(Event::<T>::GameStarted { game_id: move_hash, owner, round_length, bet });
-            Ok(())
+    fn award_prize(
+        game_id: &GameId<T>,
+        winner: T::AccountId,
+        (_, collection, item): PrizeOf<T>,
+    ) {
+        if Self::transfer_prize(game_id, &winner, collection, item) {
+            Self::deposit_event(Event::<T>::PrizeAwarded { game_id: *game_id, winner, collection, item });
         }
+    }
 
-        /// Place a bet on an existing game. The game must be in "betting" stage. A player needs to
-        /// provide a game id and a move hash.
-        #[pallet::weight(T::WeightInfo::join_game())]
-        pub fn join(
-            origin: OriginFor<T>,
-            game_id: GameId<T>,
-            move_hash: T::MoveHash,
-        ) -> DispatchResult {
-            let joiner = ensure_signed(origin)?;
-            let game = Games::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
-            ensure!(Self::can_join_game(&game), Error::<T>::GameWrongStage);
-            ensure!(Self::can_make_move(&game_id, &joiner), Error::<T>::PlayerMoveMade);
+    fn return_prize(game_id: &GameId<T>, (owner, collection, item): PrizeOf<T>) {
+        if Self::transfer_prize(game_id, &owner, collection, item) {
+            Self::deposit_event(Event::<T>::PrizeReturned { game_id: *game_id, owner, collection, item });
+        }
+    }
 
-            Self::deposit_bet(&joiner, game.bet)?;
-            Self::join_game(&game_id, &joiner, move_hash);
+    /// Transfers the prize item of the game out of the pallet account. The item is kept for the
+    /// account to claim if the transfer fails, e.g. because the item got frozen.
+    fn transfer_prize(
+        game_id: &GameId<T>,
+        dest: &T::AccountId,
+        collection: CollectionIdOf<T>,
+        item: ItemIdOf<T>,
+    ) -> bool {
+        let transferred = <T::Items as nonfungibles::Transfer<_>>::transfer(&collection, &item, dest).is_ok();
+        if !transferred {
+            GamePrizes::<T>::insert(game_id, (dest, collection, item));
+            Self::deposit_event(Event::<T>::PrizeUnclaimed {
+                game_id: *game_id,
+                player: dest.clone(),
+                collection,
+                item,
+            });
+        }
+        transferred
+    }
 
-            Self::deposit_event(Event::<T>::BetPlaced { game_id, player: joiner });
-            Ok(())
+    fn enter_game(player: &T::AccountId) {
+        ActiveGames::<T>::mutate(player, |count| *count = count.saturating_add(1));
+    }
+
+    /// The spending cap of the account, including a pending change once its period started.
+    pub(crate) fn spending_cap(player: &T::AccountId) -> Option<BalanceOf<T>> {
+        match PendingSpendingCaps::<T>::get(player) {
+            Some((period, cap)) if period <= Self::spending_period() => cap,
+            _ => SpendingCaps::<T>::get(player),
         }
+    }
 
-        /// Reveal a move. The game must be in "revealing" stage. A player needs to provide a game id
-        /// and a move reveal. The move reveal will be hashed and compared with the move hash.
-        /// The first byte of the reveal is the move itself. The rest of the reveal is the salt.
-        /// The actual move should be one of the following: 0 - Rock, 1 - Paper, 2 - Scissors.
-        #[pallet::weight(T::WeightInfo::reveal_move(move_reveal.len() as u32))]
-        pub fn reveal(
-            origin: OriginFor<T>,
-            game_id: GameId<T>,
-            move_reveal: Vec<u8>,
-        ) -> DispatchResult {
-            let player = ensure_signed(origin)?;
-            let game = Games::<T>::get(&game_id).ok_or(Error::<T>::GameMissing)?;
-            ensure!(Self::can_reveal_move(&game), Error::<T>::GameWrongStage);
+    fn record_spending(player: &T::AccountId, bet: BalanceOf<T>) {
+        if Self::spending_cap(player).is_some() {
+            let period = Self::spending_period();
+            Spendings::<T>::mutate(player, |spending| {
+                let spent = spending.filter(|(spent_period, _)| *spent_period == period)
+                    .map_or(bet, |(_, spent)| spent.saturating_add(bet));
+                *spending = Some((period, spent));
+            });
+        }
+    }
 
-            let is_last = Self::try_reveal_move(&game_id, &player, &move_reveal)?;
-            Self::deposit_event(Event::<T>::MoveRevealed { game_id, player });
+    fn deposit_bet(player: &T::AccountId, asset: Option<AssetIdOf<T>>, bet: BalanceOf<T>) -> Result<(), Error<T>> {
+        match asset {
+            None => T::Currency::transfer(
+                player,
+                &Self::account_id(),
+                bet,
+                ExistenceRequirement::KeepAlive)
+                .map(|_| Escrow::<T>::mutate(|escrow| *escrow = escrow.saturating_add(bet)))
+                .map_err(|_| Error::<T>::MoneyNotEnough)?,
+            Some(asset) => <T::Assets as fungibles::Transfer<_>>::transfer(
+                asset,
+                player,
+                &Self::account_id(),
+                bet,
+                true)
+                .map(|_| ())
+                .map_err(|_| Error::<T>::MoneyNotEnough)?,
+        }
 
-            if is_last {
-                Self::end_game(&game_id);
-            }
-            Ok(())
+        Self::enter_game(player);
+        // spending caps only apply to the native currency
+        if asset.is_none() {
+            Self::record_spending(player, bet);
         }
+        Ok(())
     }
-}
 
-impl<T: Config> Pallet<T> {
-    fn now() -> T::BlockNumber {
-        frame_system::Pallet::<T>::block_number()
+    /// Pays the amount out of the bets of the game played for the given asset. The amount is kept
+    /// for the player to claim if the transfer fails.
+    fn pay_out(player: &T::AccountId, asset: Option<AssetIdOf<T>>, amount: BalanceOf<T>) {
+        if Self::transfer_out(player, asset, amount).is_err() {
+            UnclaimedPayouts::<T>::mutate(player, asset, |unclaimed| *unclaimed = unclaimed.saturating_add(amount));
+            Self::deposit_event(Event::<T>::PayoutUnclaimed { player: player.clone(), asset, amount });
+        }
     }
 
-    fn deposit_bet(player: &T::AccountId, bet: BalanceOf<T>) -> Result<(), Error<T>> {
-        T::Currency::withdraw(
-            player,
-            bet,
-            WithdrawReasons::RESERVE,
-            ExistenceRequirement::KeepAlive)
-            .map(|_| ())
-            .map_err(|_| Error::<T>::MoneyNotEnough)
+    /// Transfers the amount of the asset, `None` for the native currency, out of the pallet account.
+    fn transfer_out(dest: &T::AccountId, asset: Option<AssetIdOf<T>>, amount: BalanceOf<T>) -> DispatchResult {
+        match asset {
+            // the pallet account holds the escrow of the native games, the jackpot and the season
+            // prize pool
+            None => T::Currency::transfer(&Self::account_id(), dest, amount, ExistenceRequirement::KeepAlive),
+            // the pallet account holds the bets of every asset game in progress
+            Some(asset) => <T::Assets as fungibles::Transfer<_>>::transfer(
+                asset,
+                &Self::account_id(),
+                dest,
+                amount,
+                false)
+                .map(|_| ()),
+        }
+    }
+
+    /// Ensures the player may place the bet in a new game according to its limits.
+    fn ensure_can_play(
+        player: &T::AccountId,
+        asset: Option<AssetIdOf<T>>,
+        bet: BalanceOf<T>,
+    ) -> Result<(), Error<T>> {
+        ensure!(!Self::is_self_excluded(player), Error::<T>::PlayerSelfExcluded);
+        ensure!(Self::has_free_game_slot(player), Error::<T>::TooManyActiveGames);
+        ensure!(
+            asset.is_some() || Self::within_spending_cap(player, bet),
+            Error::<T>::SpendingCapExceeded
+        );
+        Ok(())
+    }
+
+    /// The index of the current spending period.
+    pub(crate) fn spending_period() -> T::BlockNumber {
+        let period: T::BlockNumber = T::SpendingPeriod::get().max(1).into();
+        Self::now() / period
+    }
+
+    fn leave_game(player: &T::AccountId) {
+        ActiveGames::<T>::mutate_exists(player, |maybe_count| {
+            *maybe_count = maybe_count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+        });
+    }
+
+    fn reserve_deposit(owner: &T::AccountId, game_id: &GameId<T>) -> Result<BalanceOf<T>, Error<T>> {
+        let deposit = T::GameDeposit::get();
+        T::Currency::reserve(owner, deposit).map_err(|_| Error::<T>::MoneyNotEnough)?;
+        GameDeposits::<T>::insert(game_id, (owner, deposit));
+        Ok(deposit)
+    }
+
+    fn release_deposit(game_id: &GameId<T>) {
+        if let Some((owner, amount)) = GameDeposits::<T>::take(game_id) {
+            T::Currency::unreserve(&owner, amount);
+            Self::deposit_event(Event::<T>::DepositReleased { game_id: *game_id, owner, amount });
+        }
+    }
+
+    fn slash_deposit(game_id: &GameId<T>) {
+        if let Some((owner, amount)) = GameDeposits::<T>::take(game_id) {
+            // the slashed funds are burned just like the lost bets
+            let _ = T::Currency::slash_reserved(&owner, amount);
+            Self::deposit_event(Event::<T>::DepositSlashed { game_id: *game_id, owner, amount });
+        }
     }
 
     fn start_game(owner: &T::AccountId, move_hash: T::MoveHash, round_length: T::BlockNumber, bet: BalanceOf<T>) {
@@ -308,14 +2234,74 @@ impl<T: Config> Pallet<T> {
         Moves::<T>::insert(game_id, joiner, Move::new(move_hash));
     }
 
-    fn end_betting(game_id: &GameId<T>) {
+    /// Removes the state of a game which was already taken out of `Games` and its index and
+    /// returns the bets, the prize item and the side-bet stakes. Returns the players of the game.
+    fn refund_game(game_id: &GameId<T>, game: &GameOf<T>) -> Vec<T::AccountId> {
+        let asset = GameAssets::<T>::take(game_id);
+        let prize = GamePrizes::<T>::take(game_id);
+        GameTieBreaks::<T>::remove(game_id);
+        if TeamGames::<T>::take(game_id).is_some() {
+            let _ = TeamMembers::<T>::clear_prefix(game_id, u32::MAX, None);
+        }
+        JackpotRollovers::<T>::remove(game_id);
+        RematchGames::<T>::remove(game_id);
+        let players = Moves::<T>::drain_prefix(game_id)
+            .map(|(player, _)| {
+                if !Self::is_prize_owner(&prize, &player) {
+                    Self::pay_out(&player, asset, game.bet);
+                }
+                Self::leave_game(&player);
+                player
+            })
+            .collect::<Vec<_>>();
+
+        if asset.is_none() {
+            let bettors = players.len().saturating_sub(prize.is_some() as usize);
+            let money_pool = game.bet.saturating_mul(BalanceOf::<T>::saturated_from(bettors));
+            Escrow::<T>::mutate(|escrow| *escrow = escrow.saturating_sub(money_pool));
+        }
+        if let Some(prize) = prize {
+            Self::return_prize(game_id, prize);
+        }
+        Self::release_deposit(game_id);
+        Self::settle_side_bets(game_id, &[]);
+
+        players
+    }
+
+    /// Starts the revealing of a game. A team game whose teams are not both full is cancelled
+    /// and refunded instead, returning the weight of the refund.
+    fn end_betting(game_id: &GameId<T>) -> Weight {
+        if let Some(team_size) = TeamGames::<T>::get(game_id) {
+            if !Self::has_full_teams(game_id, team_size) {
+                let side_bets = SideBetCounts::<T>::get(game_id);
+                return match Games::<T>::take(game_id) {
+                    Some(game) => {
+                        let players = Self::refund_game(game_id, &game);
+                        let weight = T::WeightInfo::force_refund(players.len() as u32)
+                            .saturating_add(T::WeightInfo::settle_side_bets(side_bets));
+                        Self::deposit_event(Event::<T>::Refunded { game_id: *game_id, players, amount: game.bet });
+                        weight
+                    },
+                    None => 0,
+                };
+            }
+        }
+
         Games::<T>::mutate(game_id, |maybe_game| {
             maybe_game.as_mut().map(|game| {
+                // a scheduled game nobody joined forfeits its share of the schedule deposit
+                if game.stage == (GameStage::Betting { participating_players: 0 }) {
+                    Self::slash_deposit(game_id);
+                }
                 game.start_revealing();
                 let timeout = Self::now().saturating_add(game.round_length);
                 RevealingGamesIndex::<T>::append(timeout, game_id);
+
+                Self::deposit_event(Event::<T>::RevealingStarted { game_id: *game_id, deadline: timeout });
             })
         });
+        0
     }
 
     fn try_reveal_move(
@@ -334,10 +2320,12 @@ impl<T: Config> Pallet<T> {
                 Ok(())
             })?;
 
+        // a game with a random tie-break waits for the randomness at its deadline
+        let draws_tie = GameTieBreaks::<T>::get(game_id) == Some(TieBreak::Random);
         let mut is_last = false;
         Games::<T>::mutate(game_id, |maybe_game| {
             maybe_game.as_mut().map(|game| {
-                if game.last_revealing() {
+                if game.last_revealing() && !draws_tie {
                     is_last = true
                 } else {
                     game.reveal()
@@ -348,30 +2336,723 @@ impl<T: Config> Pallet<T> {
         Ok(is_last)
     }
 
-    fn end_game(game_id: &GameId<T>) {
+    /// Removes the game from the index of its current stage.
+    fn remove_from_index(game_id: &GameId<T>, game: &GameOf<T>) {
+        let remove = |maybe_game_ids: &mut Option<Vec<GameId<T>>>| {
+            if let Some(game_ids) = maybe_game_ids {
+                game_ids.retain(|id| id != game_id);
+                if game_ids.is_empty() {
+                    *maybe_game_ids = None;
+                }
+            }
+        };
+
+        match game.stage {
+            GameStage::Betting { .. } =>
+                BettingGamesIndex::<T>::mutate_exists(game.start.saturating_add(game.round_length), remove),
+            GameStage::Revealing { .. } =>
+                RevealingGamesIndex::<T>::mutate_exists(Self::reveal_deadline(game), remove),
+        }
+    }
+
+    /// The block the "revealing" stage of the game ends at.
+    fn reveal_deadline(game: &GameOf<T>) -> T::BlockNumber {
+        // revealing starts right at the end of the betting stage and lasts another round
+        game.start.saturating_add(game.round_length).saturating_add(game.round_length)
+    }
+
+    /// Ends the game at its reveal deadline. The game with a random tie-break is deferred to the
+    /// next block until there is a seed which was not known before the deadline.
+    fn end_revealing(game_id: &GameId<T>, now: T::BlockNumber) {
+        let deadline = match Games::<T>::get(game_id) {
+            Some(game) if matches!(game.stage, GameStage::Revealing { .. }) => Self::reveal_deadline(&game),
+            // the entries of the games which already ended are skipped
+            _ => return,
+        };
+        // as well as the stale entries of an ended game before the deadline of a game started again
+        // with the same id
+        if deadline > now {
+            return;
+        }
+
+        match GameTieBreaks::<T>::get(game_id) {
+            Some(TieBreak::Random) => match Self::tie_break_seed(game_id, deadline) {
+                Some(seed) => Self::end_game(game_id, Some(seed)),
+                None => RevealingGamesIndex::<T>::append(now.saturating_add(One::one()), game_id),
+            },
+            _ => Self::end_game(game_id, None),
+        }
+    }
+
+    /// The seed of the random tie-break of the game, `None` if the randomness could have been known
+    /// by the players when they last could reveal, before the deadline.
+    fn tie_break_seed(game_id: &GameId<T>, deadline: T::BlockNumber) -> Option<T::Hash> {
+        let (seed, known_since) = T::Randomness::random(&(b"ropasci/tie", game_id).encode());
+        Some(seed).filter(|_| known_since >= deadline)
+    }
+
+    fn end_game(game_id: &GameId<T>, tie_break_seed: Option<T::Hash>) {
         Games::<T>::mutate_exists(game_id, |maybe_game| {
             if let Some(game) = maybe_game.take() {
                 let moves = Moves::<T>::drain_prefix(game_id).collect::<Vec<_>>();
-                let players_count = BalanceOf::<T>::saturated_from(moves.len());
-                let money_pool = game.bet.saturating_mul(players_count);
-                let mut winners = Self::find_winners(&moves);
+                Self::settle_deposit(game_id, &game, &moves);
+                let asset = GameAssets::<T>::take(game_id);
+                let prize = GamePrizes::<T>::take(game_id);
+                // the owner of a prize game escrows the item instead of the bet
+                let bettors = moves.len().saturating_sub(prize.is_some() as usize);
+                let money_pool = game.bet.saturating_mul(BalanceOf::<T>::saturated_from(bettors));
+                let teams = TeamGames::<T>::take(game_id)
+                    .map(|_| TeamMembers::<T>::drain_prefix(game_id).collect::<BTreeMap<_, _>>());
+                let winners = match &teams {
+                    Some(teams) => Self::find_team_winners(teams, &moves),
+                    None => Self::find_winners(&moves),
+                };
+                RematchGames::<T>::remove(game_id);
+                // the jackpot is only won by a sole winner of the game, not by the one of a tie-break
+                let sole_winner = match winners.as_slice() {
+                    [winner] => Some(winner.clone()),
+                    _ => None,
+                };
+                let winners = Self::break_tie(game_id, winners, tie_break_seed);
                 let winners_count = BalanceOf::<T>::saturated_from(winners.len());
-                let reward = money_pool.checked_div(&winners_count)
-                    .unwrap_or_else(|| {
-                        // return all bets if something went wrong
-                        winners = moves.into_iter().map(|(player, _)| player).collect();
-                        game.bet
+                let rake = if winners.is_empty() { Zero::zero() } else { PalletConfig::<T>::get().rake * money_pool };
+                // the profits of the asset and prize games are not comparable with the native ones
+                // and only the native games take part in the jackpot
+                let counts_profit = asset.is_none() && prize.is_none();
+                let rolls_over = JackpotRollovers::<T>::take(game_id).is_some() && winners.is_empty();
+                let jackpot_cut = match (counts_profit, winners.is_empty()) {
+                    (true, true) if rolls_over => money_pool,
+                    (true, false) => T::JackpotShare::get() * money_pool,
+                    _ => Zero::zero(),
+                };
+                let reward = money_pool.saturating_sub(rake).saturating_sub(jackpot_cut)
+                    .checked_div(&winners_count).unwrap_or_default();
+                match asset {
+                    None => {
+                        Escrow::<T>::mutate(|escrow| *escrow = escrow.saturating_sub(money_pool));
+                        Self::collect_rake(rake);
+                    }
+                    Some(asset) if !rake.is_zero() => {
+                        AssetRakes::<T>::mutate(asset, |collected| *collected = collected.saturating_add(rake));
+                    }
+                    Some(_) => {}
+                }
+
+                let players = moves.into_iter()
+                    .map(|(player, player_move)| {
+                        let payout = match (winners.is_empty(), winners.contains(&player)) {
+                            (true, _) if rolls_over || Self::is_prize_owner(&prize, &player) => Zero::zero(),
+                            // return all bets if nobody wins
+                            (true, _) => game.bet,
+                            (false, true) => reward,
+                            (false, false) => Zero::zero(),
+                        };
+                        if !payout.is_zero() {
+                            Self::pay_out(&player, asset, payout);
+                        }
+                        Self::leave_game(&player);
+                        (player, player_move.hand, payout)
+                    })
+                    .collect::<Vec<_>>();
+
+                if winners.is_empty() && !rolls_over {
+                    Self::deposit_event(Event::<T>::Refunded {
+                        game_id: *game_id,
+                        players: players.iter().map(|(player, _, _)| player.clone()).collect(),
+                        amount: game.bet,
                     });
+                }
+
+                if counts_profit {
+                    Self::settle_jackpot(game_id, jackpot_cut, sole_winner, players.len());
+                }
+                if let Some(prize) = prize {
+                    match Self::prize_winner(game_id, &winners) {
+                        Some(winner) => Self::award_prize(game_id, winner, prize),
+                        None => Self::return_prize(game_id, prize),
+                    }
+                }
+
+                Self::record_result(game_id, &game, counts_profit, teams.is_none(), &winners, &players);
+                Self::update_ratings(&winners, &players);
+                Self::settle_side_bets(game_id, &winners);
+                T::OnGameEnded::on_game_ended(game_id, &players);
+                Self::deposit_event(Event::<T>::GameEnded { game_id: *game_id, winners, reward, players });
+            }
+        });
+    }
+
+    /// Adds the season share of the native currency rake to the season prize pool and burns the
+    /// rest of it from the pallet account.
+    fn collect_rake(rake: BalanceOf<T>) {
+        let season_cut = T::SeasonRakeShare::get() * rake;
+        SeasonPrizePool::<T>::mutate(|pool| *pool = pool.saturating_add(season_cut));
+        let _ = T::Currency::withdraw(
+            &Self::account_id(),
+            rake.saturating_sub(season_cut),
+            WithdrawReasons::FEE,
+            ExistenceRequirement::KeepAlive);
+    }
+
+    /// Adds the cut of the pot of the game to the jackpot and pays the jackpot out to the sole
+    /// winner of a game of at least `JackpotMinPlayers` players.
+    fn settle_jackpot(
+        game_id: &GameId<T>,
+        jackpot_cut: BalanceOf<T>,
+        sole_winner: Option<T::AccountId>,
+        players: usize,
+    ) {
+        if !jackpot_cut.is_zero() {
+            let jackpot = Jackpot::<T>::mutate(|jackpot| {
+                *jackpot = jackpot.saturating_add(jackpot_cut);
+                *jackpot
+            });
+            Self::deposit_event(Event::<T>::JackpotIncreased { game_id: *game_id, amount: jackpot_cut, jackpot });
+        }
+
+        if let Some(winner) = sole_winner {
+            if players >= T::JackpotMinPlayers::get() as usize && !Jackpot::<T>::get().is_zero() {
+                let amount = Jackpot::<T>::take();
+                Self::pay_out(&winner, None, amount);
+                Self::deposit_event(Event::<T>::JackpotWon { game_id: *game_id, winner, amount });
+            }
+        }
+    }
+
+    /// Splits all the side-bet stakes of the game among the spectators who backed a winner in
+    /// proportion to their stakes. Returns the stakes if there are no such spectators. The
+    /// remainder of the rounded down shares is added to the jackpot.
+    fn settle_side_bets(game_id: &GameId<T>, winners: &[T::AccountId]) {
+        SideBetCounts::<T>::remove(game_id);
+        let side_bets = SideBets::<T>::drain_prefix(game_id).collect::<Vec<_>>();
+        if side_bets.is_empty() {
+            return;
+        }
+
+        let (total, backed_winners) = side_bets.iter()
+            .fold((BalanceOf::<T>::zero(), BalanceOf::<T>::zero()), |(total, backed_winners), (_, (player, stake))| (
+                total.saturating_add(*stake),
+                if winners.contains(player) { backed_winners.saturating_add(*stake) } else { backed_winners },
+            ));
+        Escrow::<T>::mutate(|escrow| *escrow = escrow.saturating_sub(total));
+
+        let payouts = side_bets.into_iter()
+            .map(|(spectator, (player, stake))| {
+                let payout = if backed_winners.is_zero() {
+                    stake
+                } else if winners.contains(&player) {
+                    multiply_by_rational_with_rounding(
+                        stake.saturated_into(),
+                        total.saturated_into(),
+                        backed_winners.saturated_into(),
+                        Rounding::Down)
+                        .map_or_else(Zero::zero, BalanceOf::<T>::saturated_from)
+                } else {
+                    Zero::zero()
+                };
+                if !payout.is_zero() {
+                    Self::pay_out(&spectator, None, payout);
+                }
+                (spectator, payout)
+            })
+            .collect::<Vec<_>>();
 
-                for winner in &winners {
-                    T::Currency::deposit_creating(winner, reward);
+        let remainder = payouts.iter().fold(total, |remainder, (_, payout)| remainder.saturating_sub(*payout));
+        if !remainder.is_zero() {
+            let jackpot = Jackpot::<T>::mutate(|jackpot| {
+                *jackpot = jackpot.saturating_add(remainder);
+                *jackpot
+            });
+            Self::deposit_event(Event::<T>::JackpotIncreased { game_id: *game_id, amount: remainder, jackpot });
+        }
+
+        Self::deposit_event(Event::<T>::SideBetsSettled { game_id: *game_id, payouts });
+    }
+
+    /// Releases the deposit of the game unless the game got to the "revealing" stage and its owner
+    /// did not reveal the move.
+    fn settle_deposit(game_id: &GameId<T>, game: &GameOf<T>, moves: &[(T::AccountId, MoveOf<T>)]) {
+        let owner_revealed = GameDeposits::<T>::get(game_id)
+            .and_then(|(owner, _)| moves.iter().find(|(player, _)| *player == owner))
+            .map_or(true, |(_, owner_move)| owner_move.hand.is_some());
+
+        match game.stage {
+            GameStage::Revealing { .. } if !owner_revealed => Self::slash_deposit(game_id),
+            _ => Self::release_deposit(game_id),
+        }
+    }
+
+    /// Records the result of the game. The profits are only counted if `counts_profit` is set, the
+    /// game can be rematched if it also was not a team game.
+    fn record_result(
+        game_id: &GameId<T>,
+        game: &GameOf<T>,
+        counts_profit: bool,
+        without_teams: bool,
+        winners: &[T::AccountId],
+        players: &[(T::AccountId, Option<Hand>, BalanceOf<T>)],
+    ) {
+        let season = CurrentSeason::<T>::get();
+        for (player, hand, payout) in players {
+            let (bet, payout) = if counts_profit {
+                (game.bet.saturated_into(), (*payout).saturated_into())
+            } else {
+                (0, 0)
+            };
+            PlayerStats::<T>::mutate(player, |stats| stats.record(*hand, winners.contains(player), bet, payout));
+            if counts_profit {
+                Self::update_leaderboard(season, player, net_profit(bet, payout));
+            }
+        }
+
+        // a game never has more than `MaxPlayers` players as `GameConfig::max_players` is bounded by it
+        if let (Ok(winners), Ok(players)) = (winners.to_vec().try_into(), players.to_vec().try_into()) {
+            let now = Self::now();
+            GameResults::<T>::insert(game_id, GameResult {
+                ended_at: now,
+                round_length: game.round_length,
+                bet: game.bet,
+                winners,
+                players,
+                rematchable: counts_profit && without_teams,
+            });
+            GameResultsIndex::<T>::append(now.saturating_add(T::ResultsRetention::get().into()), game_id);
+        }
+    }
+
+    fn update_leaderboard(season: u32, player: &T::AccountId, profit: i128) {
+        let season_profit = SeasonProfits::<T>::mutate(season, player, |season_profit| {
+            *season_profit = season_profit.saturating_add(profit);
+            *season_profit
+        });
+
+        Leaderboard::<T>::mutate(|leaderboard| {
+            let mut entries = leaderboard.to_vec();
+            entries.retain(|(account, _)| account != player);
+            if season_profit > 0 {
+                let position = entries.iter()
+                    .position(|(_, profit)| *profit < season_profit)
+                    .unwrap_or(entries.len());
+                entries.insert(position, (player.clone(), season_profit));
+            }
+            entries.truncate(T::LeaderboardSize::get() as usize);
+
+            if let Ok(entries) = entries.try_into() {
+                *leaderboard = entries;
+            }
+        });
+    }
+
+    fn end_season() -> Weight {
+        let season = CurrentSeason::<T>::mutate(|season| {
+            *season = season.saturating_add(1);
+            *season - 1
+        });
+        let standings = Leaderboard::<T>::take();
+        let prize_pool = SeasonPrizePool::<T>::get();
+
+        // the prizes are proportional to the reversed rank on the leaderboard
+        let places = standings.len() as u32;
+        let total_shares = places.saturating_mul(places.saturating_add(1)) / 2;
+        let prizes = standings.into_iter()
+            .enumerate()
+            .map(|(rank, (player, _))| {
+                let prize = Perbill::from_rational(places - rank as u32, total_shares).mul_floor(prize_pool);
+                Self::pay_out(&player, None, prize);
+                (player, prize)
+            })
+            .collect::<Vec<_>>();
+
+        let paid_out = prizes.iter()
+            .fold(BalanceOf::<T>::zero(), |total, (_, prize)| total.saturating_add(*prize));
+        SeasonPrizePool::<T>::put(prize_pool.saturating_sub(paid_out));
+
+        let weight = T::DbWeight::get().reads_writes(
+            3u64.saturating_add(prizes.len() as Weight),
+            3u64.saturating_add(prizes.len() as Weight),
+        );
+        Self::deposit_event(Event::<T>::SeasonEnded { season, prizes });
+        weight
+    }
+
+    /// Removes up to `SeasonClearLimit` net profits of the oldest past season which has any left.
+    fn clear_past_season() -> Weight {
+        let season = ClearedSeasons::<T>::get();
+        if season >= CurrentSeason::<T>::get() {
+            return T::DbWeight::get().reads(2);
+        }
+
+        // the keys removed in the previous blocks are gone, so no cursor is needed
+        let removed = SeasonProfits::<T>::clear_prefix(season, T::SeasonClearLimit::get(), None);
+        if removed.maybe_cursor.is_none() {
+            ClearedSeasons::<T>::put(season.saturating_add(1));
+        }
+        T::DbWeight::get().reads_writes(
+            2u64.saturating_add(removed.loops as Weight),
+            1u64.saturating_add(removed.unique as Weight),
+        )
+    }
+
+    fn update_ratings(winners: &[T::AccountId], players: &[(T::AccountId, Option<Hand>, BalanceOf<T>)]) {
+        let (player_1, player_2) = match players {
+            // a duel nobody played does not affect the ratings
+            [(_, None, _), (_, None, _)] => return,
+            [(player_1, _, _), (player_2, _, _)] => (player_1, player_2),
+            _ => return,
+        };
+
+        let (outcome_1, outcome_2) = match (winners.contains(player_1), winners.contains(player_2)) {
+            (true, false) => (Outcome::Win, Outcome::Loss),
+            (false, true) => (Outcome::Loss, Outcome::Win),
+            _ => (Outcome::Draw, Outcome::Draw),
+        };
+
+        let (mut rating_1, mut rating_2) = (Ratings::<T>::get(player_1), Ratings::<T>::get(player_2));
+        let (opponent_1, opponent_2) = (rating_2.value, rating_1.value);
+        rating_1.update(opponent_1, outcome_1, Self::k_factor(&rating_1));
+        rating_2.update(opponent_2, outcome_2, Self::k_factor(&rating_2));
+
+        Ratings::<T>::insert(player_1, rating_1);
+        Ratings::<T>::insert(player_2, rating_2);
+    }
+
+    fn k_factor(rating: &Rating) -> u32 {
+        if rating.is_provisional(T::ProvisionalGames::get()) {
+            T::ProvisionalKFactor::get()
+        } else {
+            T::RatingKFactor::get()
+        }
+    }
+
+    fn prune_result(game_id: &GameId<T>, now: T::BlockNumber) {
+        GameResults::<T>::mutate_exists(game_id, |maybe_result| {
+            // the game id might have been reused by a game which ended later
+            let expired = maybe_result.as_ref()
+                .map(|result| result.ended_at.saturating_add(T::ResultsRetention::get().into()) <= now)
+                .unwrap_or(false);
+            if expired {
+                *maybe_result = None;
+            }
+        });
+    }
+
+    fn new_rematch(game_id: &GameId<T>) -> Result<RematchOf<T>, Error<T>> {
+        let result = GameResults::<T>::get(game_id).ok_or(Error::<T>::GameMissing)?;
+        let config = PalletConfig::<T>::get();
+        let rematch_id = Self::rematch_id(game_id);
+        ensure!(
+            result.rematchable &&
+                Self::can_create_game(&rematch_id) &&
+                !GameResults::<T>::contains_key(rematch_id) &&
+                result.players.len() >= 2 &&
+                // the betting stage of the rematch is accounted to have ended when it starts
+                result.round_length <= Self::now() &&
+                Self::valid_round_length(&config, result.round_length) &&
+                Self::valid_bet(&config, result.bet),
+            Error::<T>::RematchUnavailable
+        );
+
+        Ok(Rematch {
+            round_length: result.round_length,
+            bet: result.bet,
+            players: result.players.into_iter().map(|(player, _, _)| player).collect(),
+            expires_at: Self::now().saturating_add(T::RematchWindow::get().max(1).into()),
+            moves: Vec::new(),
+        })
+    }
+
+    /// The id of the game the rematch of the previous game is played as.
+    fn rematch_id(previous: &GameId<T>) -> GameId<T> {
+        T::MoveHasher::hash_of(&(b"ropasci/rematch", previous))
+    }
+
+    /// Starts the rematch with all the moves committed in "revealing" stage, or returns the bets if
+    /// its game id is taken.
+    fn start_rematch(previous: &GameId<T>, rematch: RematchOf<T>) {
+        let game_id = Self::rematch_id(previous);
+        ReservedGameIds::<T>::remove(game_id);
+        // the betting stage is over already, so it is accounted to have ended right now
+        let start = match Self::now().checked_sub(&rematch.round_length) {
+            Some(start) if Self::can_create_game(&game_id) => start,
+            _ => {
+                Self::refund_rematch(previous, rematch);
+                return;
+            }
+        };
+
+        let owner = rematch.moves[0].0.clone();
+        Games::<T>::insert(game_id, Game::open(start, rematch.round_length, rematch.bet));
+        for (player, move_hash) in rematch.moves {
+            Self::join_game(&game_id, &player, move_hash);
+        }
+        RematchGames::<T>::insert(game_id, previous);
+
+        Self::deposit_event(Event::<T>::GameStarted {
+            game_id,
+            owner,
+            round_length: rematch.round_length,
+            bet: rematch.bet,
+            asset: None,
+            deposit: Zero::zero(),
+            schedule: None,
+        });
+        Self::end_betting(&game_id);
+        Self::deposit_event(Event::<T>::RematchStarted { game_id, previous: *previous });
+    }
+
+    fn expire_rematch(previous: &GameId<T>) {
+        if let Some(rematch) = Rematches::<T>::take(previous) {
+            Self::refund_rematch(previous, rematch);
+        }
+    }
+
+    fn refund_rematch(previous: &GameId<T>, rematch: RematchOf<T>) {
+        ReservedGameIds::<T>::remove(Self::rematch_id(previous));
+        let players = rematch.moves.into_iter()
+            .map(|(player, _)| {
+                Escrow::<T>::mutate(|escrow| *escrow = escrow.saturating_sub(rematch.bet));
+                Self::pay_out(&player, None, rematch.bet);
+                Self::leave_game(&player);
+                player
+            })
+            .collect();
+
+        Self::deposit_event(Event::<T>::RematchExpired { game_id: *previous, players, amount: rematch.bet });
+    }
+
+    /// The id of the game a schedule opens at the given block.
+    fn scheduled_game_id(schedule_id: ScheduleId, opens_at: T::BlockNumber) -> GameId<T> {
+        T::MoveHasher::hash_of(&(b"ropasci/schedule", schedule_id, opens_at))
+    }
+
+    /// Opens the next game of the schedule unless the games are paused or the config changed so
+    /// that the schedule parameters are no longer valid, and indexes the schedule at its next game
+    /// whose block has room for it. The skipped games are reported by `ScheduledGameSkipped`.
+    fn open_scheduled_game(schedule_id: ScheduleId, now: T::BlockNumber) {
+        let mut schedule = match Schedules::<T>::get(schedule_id) {
+            Some(schedule) => schedule,
+            None => return,
+        };
+
+        let config = PalletConfig::<T>::get();
+        let game_id = Self::scheduled_game_id(schedule_id, now);
+        ReservedGameIds::<T>::remove(game_id);
+        if !Paused::<T>::get() &&
+            Self::valid_round_length(&config, schedule.round_length) &&
+            Self::valid_bet(&config, schedule.bet) &&
+            Self::can_create_game(&game_id) {
+            let game = Game::open(now, schedule.round_length, schedule.bet);
+            Games::<T>::insert(game_id, &game);
+            BettingGamesIndex::<T>::append(now.saturating_add(game.round_length), game_id);
+
+            // the game holds an equal share of the rest of the schedule deposit until it ends
+            let deposit = schedule.deposit / BalanceOf::<T>::from(schedule.repeats.saturating_add(1));
+            schedule.deposit = schedule.deposit.saturating_sub(deposit);
+            GameDeposits::<T>::insert(game_id, (schedule.owner.clone(), deposit));
+
+            Self::deposit_event(Event::<T>::GameStarted {
+                game_id,
+                owner: schedule.owner.clone(),
+                round_length: schedule.round_length,
+                bet: schedule.bet,
+                asset: None,
+                deposit,
+                schedule: Some(schedule_id),
+            });
+        } else {
+            Self::deposit_event(Event::<T>::ScheduledGameSkipped { schedule_id, game_id });
+        }
+
+        let mut next = None;
+        if let Some(period) = schedule.period {
+            let mut opens_at = now;
+            while schedule.repeats > 0 {
+                schedule.repeats -= 1;
+                opens_at = opens_at.saturating_add(period);
+                if Self::has_schedule_room(opens_at) {
+                    next = Some(opens_at);
+                    break;
+                }
+                let game_id = Self::scheduled_game_id(schedule_id, opens_at);
+                Self::deposit_event(Event::<T>::ScheduledGameSkipped { schedule_id, game_id });
+            }
+        }
+
+        match next {
+            Some(opens_at) => {
+                SchedulesIndex::<T>::append(opens_at, schedule_id);
+                ReservedGameIds::<T>::insert(Self::scheduled_game_id(schedule_id, opens_at), ());
+                Schedules::<T>::insert(schedule_id, GameSchedule { opens_at, ..schedule });
+            }
+            None => {
+                Schedules::<T>::remove(schedule_id);
+                T::Currency::unreserve(&schedule.owner, schedule.deposit);
+                Self::deposit_event(Event::<T>::ScheduleCompleted { schedule_id });
+            }
+        }
+    }
+
+    fn remove_from_challenges_index(at: T::BlockNumber, game_id: &GameId<T>) {
+        ChallengesIndex::<T>::mutate_exists(at, |maybe_game_ids| {
+            if let Some(game_ids) = maybe_game_ids {
+                game_ids.retain(|id| id != game_id);
+                if game_ids.is_empty() {
+                    *maybe_game_ids = None;
                 }
+            }
+        });
+    }
+
+    /// Ends a challenge at its index block. The challenger forfeits an answered challenge, an
+    /// unanswered one is cancelled and the stake returned.
+    fn expire_challenge(game_id: &GameId<T>) {
+        match Challenges::<T>::get(game_id) {
+            Some(challenge) if challenge.response.is_none() => {
+                Challenges::<T>::remove(game_id);
+                Escrow::<T>::mutate(|escrow| *escrow = escrow.saturating_sub(challenge.stake));
+                Self::pay_out(&challenge.challenger, None, challenge.stake);
+                Self::leave_game(&challenge.challenger);
+
+                Self::deposit_event(Event::<T>::ChallengeCancelled { game_id: *game_id });
+            }
+            _ => Self::end_challenge(game_id, None),
+        }
+    }
 
-                Self::deposit_event(Event::<T>::GameEnded { game_id: *game_id, winners, reward });
+    /// Settles an answered challenge. The challenger forfeits the stakes if `challenger_hand` is
+    /// `None`.
+    fn end_challenge(game_id: &GameId<T>, challenger_hand: Option<Hand>) {
+        let (challenger, stake, responder, responder_hand) = match Challenges::<T>::take(game_id) {
+            Some(Challenge { challenger, stake, response: Some((responder, hand)), .. }) =>
+                (challenger, stake, responder, hand),
+            _ => return,
+        };
+
+        let winner = match challenger_hand {
+            Some(hand) if hand == responder_hand => None,
+            Some(hand) if hand.beaten_by() != responder_hand => Some(challenger.clone()),
+            _ => Some(responder.clone()),
+        };
+        let money_pool = stake.saturating_mul(2u32.into());
+        Escrow::<T>::mutate(|escrow| *escrow = escrow.saturating_sub(money_pool));
+
+        let payout = match &winner {
+            Some(winner) => {
+                let rake = PalletConfig::<T>::get().rake * money_pool;
+                Self::collect_rake(rake);
+                let reward = money_pool.saturating_sub(rake);
+                Self::pay_out(winner, None, reward);
+                reward
             }
+            // return both stakes on a draw
+            None => {
+                Self::pay_out(&challenger, None, stake);
+                Self::pay_out(&responder, None, stake);
+                stake
+            }
+        };
+        Self::leave_game(&challenger);
+        Self::leave_game(&responder);
+
+        Self::deposit_event(Event::<T>::ChallengeEnded {
+            game_id: *game_id,
+            challenger_hand,
+            responder_hand,
+            winner,
+            payout,
         });
     }
 
+    /// The account holding the bankroll of the house.
+    pub fn house_account() -> T::AccountId {
+        T::PalletId::get().into_sub_account_truncating(b"house")
+    }
+
+    /// Draws a hand uniformly from the first byte of the seed below the largest multiple of the
+    /// hands count, `None` if there is no such byte.
+    fn draw_hand(seed: &[u8]) -> Option<Hand> {
+        seed.iter()
+            .find(|&&value| value < u8::MAX - u8::MAX % 3)
+            .and_then(|&value| Hand::new(value % 3))
+    }
+
+    /// The payout of a player beating the house with the given bet.
+    pub(crate) fn house_win_payout(bet: BalanceOf<T>) -> BalanceOf<T> {
+        bet.saturating_add(T::HouseEdge::get().left_from_one() * bet)
+    }
+
+    /// Draws the house hand of a game at its draw block or forfeits the bet at its deadline.
+    fn process_house_game(game_id: &GameId<T>, now: T::BlockNumber) {
+        let game = match HouseGames::<T>::get(game_id) {
+            Some(game) => game,
+            None => return,
+        };
+
+        match game.house_hand {
+            Some(house_hand) => Self::end_house_game(game_id, None, house_hand),
+            None => {
+                let (seed, known_since) = T::Randomness::random(&(b"ropasci/house", game_id).encode());
+                // the randomness must not be known by the player when the game was started
+                let house_hand = Some(seed)
+                    .filter(|_| known_since > game.played_at)
+                    .and_then(|seed| Self::draw_hand(seed.as_ref()));
+
+                let deadline = match house_hand {
+                    Some(_) => now.saturating_add(T::HouseRoundLength::get().max(1).into()),
+                    None => now.saturating_add(One::one()),
+                };
+                HouseGames::<T>::insert(game_id, HouseGame { deadline, house_hand, ..game });
+                HouseGamesIndex::<T>::append(deadline, game_id);
+
+                if house_hand.is_some() {
+                    Self::deposit_event(Event::<T>::HouseHandDrawn { game_id: *game_id, deadline });
+                }
+            }
+        }
+    }
+
+    fn end_house_game(game_id: &GameId<T>, hand: Option<Hand>, house_hand: Hand) {
+        if let Some(game) = HouseGames::<T>::take(game_id) {
+            let payout = match hand {
+                Some(hand) if hand == house_hand => game.bet,
+                Some(hand) if hand == house_hand.beaten_by() => Self::house_win_payout(game.bet),
+                _ => Zero::zero(),
+            };
+
+            // the liability guarantees the house can cover the payout, the transfer only fails if
+            // the player cannot receive it or it would reap the house account
+            let unpaid = !payout.is_zero() && T::Currency::transfer(
+                &Self::house_account(),
+                &game.player,
+                payout,
+                ExistenceRequirement::KeepAlive)
+                .is_err();
+            HouseLiability::<T>::mutate(|liability| {
+                *liability = liability.saturating_sub(Self::house_win_payout(game.bet));
+                if unpaid {
+                    *liability = liability.saturating_add(payout);
+                }
+            });
+            if unpaid {
+                HouseDebts::<T>::mutate(&game.player, |debt| *debt = debt.saturating_add(payout));
+                Self::deposit_event(Event::<T>::HouseDebtIncurred {
+                    game_id: *game_id,
+                    player: game.player.clone(),
+                    amount: payout,
+                });
+            }
+            Self::leave_game(&game.player);
+
+            Self::deposit_event(Event::<T>::HouseGameEnded {
+                game_id: *game_id,
+                player: game.player,
+                hand,
+                house_hand,
+                payout,
+            });
+        }
+    }
+
     fn find_winners(moves: &[(T::AccountId, MoveOf<T>)]) -> Vec<T::AccountId> {
         let mut winners = Vec::new();
 
@@ -393,4 +3074,29 @@ impl<T: Config> Pallet<T> {
 
         winners
     }
-}
\ No newline at end of file
+
+    /// The revealed members of the team whose majority hand beats the hand of the other team.
+    /// A team without a hand loses to a team with one.
+    fn find_team_winners(
+        teams: &BTreeMap<T::AccountId, Team>,
+        moves: &[(T::AccountId, MoveOf<T>)],
+    ) -> Vec<T::AccountId> {
+        let team_hand = |team: Team| Hand::majority(&moves.iter()
+            .filter(|(player, _)| teams.get(player) == Some(&team))
+            .filter_map(|(_, player_move)| player_move.hand)
+            .collect::<Vec<_>>());
+
+        let winning_team = match (team_hand(Team::First), team_hand(Team::Second)) {
+            (Some(first), Some(second)) if first.beaten_by() == second => Team::Second,
+            (Some(first), Some(second)) if second.beaten_by() == first => Team::First,
+            (Some(_), None) => Team::First,
+            (None, Some(_)) => Team::Second,
+            _ => return Vec::new(),
+        };
+
+        moves.iter()
+            .filter(|(player, player_move)| teams.get(player) == Some(&winning_team) && player_move.hand.is_some())
+            .map(|(player, _)| player.clone())
+            .collect()
+    }
+}
@@ -1,16 +1,27 @@
-use frame_support::traits::{ConstU16, ConstU32, ConstU64, OnFinalize, OnInitialize};
+use frame_support::{
+    parameter_types,
+    PalletId,
+    traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, GenesisBuild, OnFinalize, OnInitialize, Randomness},
+    weights::Weight,
+};
 use frame_system as system;
+use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::H256;
 use sp_runtime::{
-    testing::Header,
+    Permill,
+    testing::{Header, TestSignature, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
 };
 
 use crate as pallet_ropasci;
+use crate::{Hand, OnGameEnded};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
+/// The asset the test accounts 1, 2 and 3 own.
+pub const ASSET: u32 = 1;
+
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
     pub enum Test where
@@ -20,6 +31,8 @@ frame_support::construct_runtime!(
     {
         System: frame_system,
         Balances: pallet_balances,
+        Assets: pallet_assets,
+        Uniques: pallet_uniques,
         RoPaSci: pallet_ropasci,
     }
 );
@@ -63,24 +76,141 @@ impl pallet_balances::Config for Test {
     type WeightInfo = ();
 }
 
+impl pallet_assets::Config for Test {
+    type Event = Event;
+    type Balance = u64;
+    type AssetId = u32;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<u64>;
+    type AssetDeposit = ConstU64<1>;
+    type AssetAccountDeposit = ConstU64<1>;
+    type MetadataDepositBase = ConstU64<1>;
+    type MetadataDepositPerByte = ConstU64<1>;
+    type ApprovalDeposit = ConstU64<1>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Extra = ();
+    type WeightInfo = ();
+}
+
+impl pallet_uniques::Config for Test {
+    type Event = Event;
+    type CollectionId = u32;
+    type ItemId = u32;
+    type Currency = Balances;
+    type ForceOrigin = EnsureRoot<u64>;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+    type Locker = ();
+    type CollectionDeposit = ConstU64<0>;
+    type ItemDeposit = ConstU64<0>;
+    type MetadataDepositBase = ConstU64<0>;
+    type AttributeDepositBase = ConstU64<0>;
+    type DepositPerByte = ConstU64<0>;
+    type StringLimit = ConstU32<50>;
+    type KeyLimit = ConstU32<32>;
+    type ValueLimit = ConstU32<64>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const RoPaSciPalletId: PalletId = PalletId(*b"ropasci!");
+    pub const SeasonRakeShare: Permill = Permill::from_percent(50);
+    pub static GameDeposit: u64 = 0;
+    pub static EndedGames: Vec<(H256, Vec<(u64, Option<Hand>, u64)>)> = vec![];
+    pub const HouseEdge: Permill = Permill::from_percent(10);
+    pub static RandomSeed: H256 = H256::zero();
+    pub static JackpotShare: Permill = Permill::zero();
+}
+
+/// Randomness returning the `RandomSeed`, known since the previous block.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(_subject: &[u8]) -> (H256, u64) {
+        (RandomSeed::get(), System::block_number().saturating_sub(1))
+    }
+}
+
+pub struct RecordEndedGames;
+
+impl OnGameEnded<u64, H256, u64> for RecordEndedGames {
+    fn on_game_ended(game_id: &H256, players: &[(u64, Option<Hand>, u64)]) {
+        let mut ended_games = EndedGames::get();
+        ended_games.push((*game_id, players.to_vec()));
+        EndedGames::set(ended_games);
+    }
+
+    fn weight(_players: u32) -> Weight {
+        0
+    }
+}
+
 impl pallet_ropasci::Config for Test {
     type Event = Event;
     type MoveHash = H256;
     type MoveHasher = BlakeTwo256;
     type Currency = Balances;
-    type MinRoundLength = ConstU32<10>;
-    type MaxRoundLength = ConstU32<50>;
+    type Assets = Assets;
+    type Items = Uniques;
+    type PalletId = RoPaSciPalletId;
+    type GameDeposit = GameDeposit;
+    type AdminOrigin = EnsureRoot<u64>;
+    type ResultsRetention = ConstU32<100>;
+    type RatingKFactor = ConstU32<16>;
+    type ProvisionalKFactor = ConstU32<32>;
+    type ProvisionalGames = ConstU32<2>;
+    type SeasonRakeShare = SeasonRakeShare;
+    type SeasonLength = ConstU32<1000>;
+    type LeaderboardSize = ConstU32<2>;
+    type SeasonClearLimit = ConstU32<2>;
+    type Randomness = TestRandomness;
+    type HouseEdge = HouseEdge;
+    type HouseDrawDelay = ConstU32<5>;
+    type HouseRoundLength = ConstU32<10>;
+    type RematchWindow = ConstU32<10>;
+    type MaxScheduleRepeats = ConstU32<3>;
+    type ScheduleDeposit = ConstU64<5>;
+    type MaxSchedulesPerBlock = ConstU32<2>;
+    type ChallengeRevealLength = ConstU32<10>;
+    type ChallengeCancelDelay = ConstU32<5>;
+    type JackpotShare = JackpotShare;
+    type JackpotMinPlayers = ConstU32<3>;
+    type MaxSideBets = ConstU32<3>;
+    type Signature = TestSignature;
+    type Signer = UintAuthorityId;
+    type UnsignedRevealPriority = ConstU64<1000>;
+    type MaxActiveGamesPerAccount = ConstU32<3>;
+    type SpendingPeriod = ConstU32<100>;
+    type OnGameEnded = (RecordEndedGames, ());
     type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with(Default::default())
+}
+
+// Build genesis storage with the given pallet genesis config.
+pub fn new_test_ext_with(genesis: pallet_ropasci::GenesisConfig<Test>) -> sp_io::TestExternalities {
+    GameDeposit::set(0);
+    EndedGames::set(vec![]);
+    RandomSeed::set(H256::zero());
+    JackpotShare::set(Permill::zero());
+
     let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
     pallet_balances::GenesisConfig::<Test> {
         balances: vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)],
     }
         .assimilate_storage(&mut storage)
         .unwrap();
+    pallet_assets::GenesisConfig::<Test> {
+        assets: vec![(ASSET, 5, true, 1)],
+        metadata: vec![],
+        accounts: vec![(ASSET, 1, 100), (ASSET, 2, 100), (ASSET, 3, 100)],
+    }
+        .assimilate_storage(&mut storage)
+        .unwrap();
+    GenesisBuild::<Test>::assimilate_storage(&genesis, &mut storage).unwrap();
     storage.into()
 }
 
@@ -95,3 +225,11 @@ pub fn run_to_block(n: u64) {
         RoPaSci::on_initialize(System::block_number());
     }
 }
+
+// Build genesis storage, execute the test and check the pallet invariants afterwards.
+pub fn build_and_execute(test: impl FnOnce()) {
+    new_test_ext().execute_with(|| {
+        test();
+        RoPaSci::do_try_state().unwrap();
+    })
+}
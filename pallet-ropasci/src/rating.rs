@@ -0,0 +1,87 @@
+use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// The rating of a new player.
+pub const INITIAL_RATING: u32 = 1500;
+
+/// Scores and expected scores are fixed-point numbers with this precision.
+const SCORE_ONE: i64 = 10_000;
+
+/// Step of the rating difference between the entries of `EXPECTED_SCORES`.
+const DIFF_STEP: u32 = 25;
+
+/// Expected score of the stronger player by the rating difference in `DIFF_STEP`s, i.e.
+/// `1 / (1 + 10^(-diff / 400))`. The difference is capped at 800.
+const EXPECTED_SCORES: [u32; 33] = [
+    5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083, 8296, 8490, 8666, 8823, 8965,
+    9091, 9203, 9302, 9390, 9468, 9536, 9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848, 9868, 9886,
+    9901,
+];
+
+/// Outcome of a duel for one of the players.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl Outcome {
+    fn score(self) -> i64 {
+        match self {
+            Outcome::Win => SCORE_ONE,
+            Outcome::Draw => SCORE_ONE / 2,
+            Outcome::Loss => 0,
+        }
+    }
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Rating {
+    pub value: u32,
+    pub games: u32,
+}
+
+impl Default for Rating {
+    fn default() -> Self {
+        Self { value: INITIAL_RATING, games: 0 }
+    }
+}
+
+impl Rating {
+    /// Whether the rating is still in its provisional period of `provisional_games` games.
+    pub fn is_provisional(&self, provisional_games: u32) -> bool {
+        self.games < provisional_games
+    }
+
+    /// Updates the rating after a duel against an opponent with the given rating using the
+    /// `k_factor` as the maximal rating change.
+    pub fn update(&mut self, opponent: u32, outcome: Outcome, k_factor: u32) {
+        let expected = expected_score(self.value, opponent) as i64;
+        let change = (k_factor as i64 * (outcome.score() - expected) + SCORE_ONE / 2)
+            .div_euclid(SCORE_ONE);
+
+        self.value = (self.value as i64).saturating_add(change).clamp(0, u32::MAX as i64) as u32;
+        self.games = self.games.saturating_add(1);
+    }
+}
+
+/// Expected score of a player against an opponent as a fixed-point number of `SCORE_ONE`
+/// precision. Linearly interpolates between the entries of `EXPECTED_SCORES`.
+fn expected_score(rating: u32, opponent: u32) -> u32 {
+    let diff = rating.abs_diff(opponent);
+    let (index, remainder) = ((diff / DIFF_STEP) as usize, diff % DIFF_STEP);
+
+    let stronger = match (EXPECTED_SCORES.get(index), EXPECTED_SCORES.get(index + 1)) {
+        (Some(low), Some(high)) => low + (high - low) * remainder / DIFF_STEP,
+        _ => EXPECTED_SCORES[EXPECTED_SCORES.len() - 1],
+    };
+
+    if rating >= opponent {
+        stronger
+    } else {
+        SCORE_ONE as u32 - stronger
+    }
+}
@@ -1,5 +1,6 @@
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::traits::Hash;
+use frame_support::{assert_noop, assert_ok, unsigned::ValidateUnsigned};
+use sp_core::H256;
+use sp_runtime::{testing::TestSignature, traits::Hash, transaction_validity::TransactionSource};
 
 use crate::game::Hand;
 use crate::mock::*;
@@ -8,7 +9,7 @@ use super::*;
 
 #[test]
 fn can_create_game() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -43,7 +44,7 @@ fn can_create_game() {
 
 #[test]
 fn fail_duplicate_game() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -58,7 +59,7 @@ fn fail_duplicate_game() {
 
 #[test]
 fn fail_wrong_round_length() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 0;
         let bet = 10;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -72,7 +73,7 @@ fn fail_wrong_round_length() {
 
 #[test]
 fn fail_not_enough_money() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = Balances::free_balance(1) + 1;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -86,7 +87,7 @@ fn fail_not_enough_money() {
 
 #[test]
 fn can_join_game() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -112,7 +113,7 @@ fn can_join_game() {
 
 #[test]
 fn fail_join_twice() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -128,7 +129,7 @@ fn fail_join_twice() {
 
 #[test]
 fn moves_to_reveal() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -158,7 +159,7 @@ fn moves_to_reveal() {
 
 #[test]
 fn fail_join_at_revealing() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -178,7 +179,7 @@ fn fail_join_at_revealing() {
 
 #[test]
 fn can_reveal() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_1 = vec![Hand::Rock as u8, 1, 2, 3];
@@ -214,7 +215,7 @@ fn can_reveal() {
 
 #[test]
 fn fail_reveal() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_1 = vec![100, 1, 2, 3]; // invalid hand
@@ -247,7 +248,7 @@ fn fail_reveal() {
 
 #[test]
 fn can_end_game() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -263,7 +264,7 @@ fn can_end_game() {
 
 #[test]
 fn can_end_game_none_revealed() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let move_hash = <Test as Config>::MoveHasher::hash(b"move");
@@ -292,7 +293,7 @@ fn can_end_game_none_revealed() {
 
 #[test]
 fn can_end_game_draw() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let same_move = vec![Hand::Rock as u8, 1, 2, 3];
@@ -321,7 +322,7 @@ fn can_end_game_draw() {
 
 #[test]
 fn can_end_game_no_win() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let (move_1, move_2, move_3) =
@@ -353,7 +354,7 @@ fn can_end_game_no_win() {
 
 #[test]
 fn can_end_game_win() {
-    new_test_ext().execute_with(|| {
+    build_and_execute(|| {
         let round_length = 20;
         let bet = 10;
         let (move_1, move_2, move_3) =
@@ -390,4 +391,2010 @@ fn can_end_game_win() {
             (b1, b2, b3, b4) if b1 == balance_1 - bet && b2 == balance_2 + bet && b3 == balance_3 + bet && b4 == balance_4 - bet
         ));
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn escrow_tracks_bets() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+
+        let held = Balances::free_balance(RoPaSci::account_id());
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_hash, move_hash));
+        assert_eq!(2 * bet, Escrow::<Test>::get());
+        assert_eq!(held + 2 * bet, Balances::free_balance(RoPaSci::account_id()));
+
+        // end game
+        run_to_block(40);
+
+        assert_eq!(0, Escrow::<Test>::get());
+        assert_eq!(held, Balances::free_balance(RoPaSci::account_id()));
+    });
+}
+
+#[test]
+fn try_state_detects_orphan_moves() {
+    new_test_ext().execute_with(|| {
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+        Moves::<Test>::insert(move_hash, 1, Move::new(move_hash));
+
+        assert!(RoPaSci::do_try_state().is_err());
+    });
+}
+
+#[test]
+fn can_query_games() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let move_1 = vec![Hand::Rock as u8];
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(b"move");
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::start(Origin::signed(2), round_length + 1, bet, move_2_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash));
+
+        assert_eq!(2, RoPaSci::open_games(0, 10).len());
+        assert_eq!(1, RoPaSci::open_games(1, 10).len());
+        assert_eq!(vec![move_1_hash], RoPaSci::games_of(1));
+        assert_eq!(2, RoPaSci::games_of(2).len());
+        assert_eq!(vec![(20, move_1_hash), (21, move_2_hash)], RoPaSci::next_deadlines());
+        assert_eq!(Some(MoveStatus::Committed), RoPaSci::player_move_status(move_1_hash, 1));
+        assert_eq!(None, RoPaSci::player_move_status(move_1_hash, 3));
+
+        // end betting of the first game
+        run_to_block(20);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+
+        assert!(matches!(
+            RoPaSci::game(move_1_hash),
+            Some(Game { stage: GameStage::Revealing { anticipated_players: 1 }, .. })
+        ));
+        assert_eq!(vec![move_2_hash], RoPaSci::open_games(0, 10).into_iter().map(|(id, _)| id).collect::<Vec<_>>());
+        assert_eq!(vec![(21, move_2_hash), (40, move_1_hash)], RoPaSci::next_deadlines());
+        assert_eq!(Some(MoveStatus::Revealed(Hand::Rock)), RoPaSci::player_move_status(move_1_hash, 1));
+    });
+}
+
+#[test]
+fn can_quote_payout() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+
+        assert_eq!(None, RoPaSci::payout_quote(move_hash, 1));
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_hash, move_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(3),  move_hash, move_hash));
+
+        assert_eq!(Some(3 * bet), RoPaSci::payout_quote(move_hash, 1));
+        assert_eq!(Some(3 * bet / 2), RoPaSci::payout_quote(move_hash, 2));
+        assert_eq!(None, RoPaSci::payout_quote(move_hash, 0));
+
+        // a sole winner of the three players also wins the jackpot
+        Jackpot::<Test>::put(5);
+        Balances::make_free_balance_be(&RoPaSci::account_id(), 3 * bet + 5);
+        assert_eq!(Some(3 * bet + 5), RoPaSci::payout_quote(move_hash, 1));
+        assert_eq!(Some(3 * bet / 2), RoPaSci::payout_quote(move_hash, 2));
+    });
+}
+
+fn ropasci_events() -> Vec<crate::Event<Test>> {
+    System::events()
+        .into_iter()
+        .filter_map(|record| match record.event {
+            mock::Event::RoPaSci(event) => Some(event),
+            _ => None,
+        })
+        .map(|event| match event {
+            // moves are stored in the hashed order of the players, sort them for comparison
+            crate::Event::GameEnded { game_id, winners, reward, mut players } => {
+                players.sort_by_key(|(player, _, _)| *player);
+                crate::Event::GameEnded { game_id, winners, reward, players }
+            }
+            crate::Event::Refunded { game_id, mut players, amount } => {
+                players.sort();
+                crate::Event::Refunded { game_id, players, amount }
+            }
+            crate::Event::SideBetsSettled { game_id, mut payouts } => {
+                payouts.sort();
+                crate::Event::SideBetsSettled { game_id, payouts }
+            }
+            event => event,
+        })
+        .collect()
+}
+
+#[test]
+fn emits_game_lifecycle_events() {
+    build_and_execute(|| {
+        // events are not deposited at genesis
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash));
+
+        // end betting
+        run_to_block(21);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2),  move_1_hash, move_2));
+
+        let events = ropasci_events();
+        assert!(events.contains(&crate::Event::RevealingStarted { game_id: move_1_hash, deadline: 41 }));
+        assert_eq!(
+            Some(&crate::Event::GameEnded {
+                game_id: move_1_hash,
+                winners: vec![2],
+                reward: 2 * bet,
+                players: vec![(1, Some(Hand::Rock), 0), (2, Some(Hand::Paper), 2 * bet)],
+            }),
+            events.last(),
+        );
+        assert!(RoPaSci::game_updates().contains(
+            &GameUpdate::RevealingStarted { game_id: move_1_hash, deadline: 41 }
+        ));
+    });
+}
+
+#[test]
+fn emits_refund_events() {
+    build_and_execute(|| {
+        // events are not deposited at genesis
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_hash, move_hash));
+
+        // end game
+        run_to_block(41);
+
+        let events = ropasci_events();
+        assert!(events.contains(&crate::Event::Refunded { game_id: move_hash, players: vec![1, 2], amount: bet }));
+        assert_eq!(
+            Some(&crate::Event::GameEnded {
+                game_id: move_hash,
+                winners: vec![],
+                reward: 0,
+                players: vec![(1, None, bet), (2, None, bet)],
+            }),
+            events.last(),
+        );
+    });
+}
+
+#[test]
+fn keeps_game_results() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+        let move_3_hash = <Test as Config>::MoveHasher::hash(b"misses reveal");
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(3),  move_1_hash, move_3_hash));
+
+        // end betting
+        run_to_block(20);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2),  move_1_hash, move_2));
+
+        // end game
+        run_to_block(40);
+
+        assert!(matches!(
+            RoPaSci::game_result(move_1_hash),
+            Some(GameResult { ended_at: 40, winners, players, .. }) if winners == vec![2] && players.len() == 3
+        ));
+        assert_eq!(
+            PlayerStatistics { games_played: 1, wins: 0, draws: 0, forfeits: 0, net_profit: -10 },
+            RoPaSci::player_stats(1),
+        );
+        assert_eq!(
+            PlayerStatistics { games_played: 1, wins: 1, draws: 0, forfeits: 0, net_profit: 20 },
+            RoPaSci::player_stats(2),
+        );
+        assert_eq!(
+            PlayerStatistics { games_played: 1, wins: 0, draws: 0, forfeits: 1, net_profit: -10 },
+            RoPaSci::player_stats(3),
+        );
+
+        // results expire
+        run_to_block(140);
+
+        assert!(!GameResults::<Test>::contains_key(move_1_hash));
+        assert!(!GameResultsIndex::<Test>::contains_key(140));
+    });
+}
+
+#[test]
+fn counts_draws() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let same_move = vec![Hand::Rock as u8, 1, 2, 3];
+        let move_hash = <Test as Config>::MoveHasher::hash(&same_move);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_hash, move_hash));
+
+        // end betting
+        run_to_block(20);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_hash, same_move.clone()));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2),  move_hash, same_move));
+
+        assert_eq!(
+            PlayerStatistics { games_played: 1, wins: 0, draws: 1, forfeits: 0, net_profit: 0 },
+            RoPaSci::player_stats(1),
+        );
+    });
+}
+
+#[test]
+fn updates_duel_ratings() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash));
+
+        // end betting
+        run_to_block(20);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2),  move_1_hash, move_2));
+
+        // provisional ratings change by half of the provisional k-factor between equal players
+        assert_eq!(Rating { value: 1484, games: 1 }, RoPaSci::rating(1));
+        assert_eq!(Rating { value: 1516, games: 1 }, RoPaSci::rating(2));
+    });
+}
+
+#[test]
+fn ignores_non_duel_ratings() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(3),  move_1_hash, move_2_hash));
+
+        // end betting
+        run_to_block(20);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2),  move_1_hash, move_2));
+
+        // end game
+        run_to_block(40);
+
+        assert_eq!(Rating::default(), RoPaSci::rating(1));
+        assert_eq!(Rating::default(), RoPaSci::rating(2));
+    });
+}
+
+#[test]
+fn pays_season_prizes() {
+    build_and_execute(|| {
+        PalletConfig::<Test>::mutate(|config| config.rake = Permill::from_percent(10));
+
+        let round_length = 20;
+        let bet = 50;
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let (move_3, move_4) = (vec![Hand::Rock as u8, 1], vec![Hand::Paper as u8, 1]);
+        let (move_1_hash, move_2_hash, move_3_hash, move_4_hash) = (
+            <Test as Config>::MoveHasher::hash(&move_1),
+            <Test as Config>::MoveHasher::hash(&move_2),
+            <Test as Config>::MoveHasher::hash(&move_3),
+            <Test as Config>::MoveHasher::hash(&move_4),
+        );
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash));
+        assert_ok!(RoPaSci::start(Origin::signed(4), round_length, bet, move_3_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(3),  move_3_hash, move_4_hash));
+
+        // end betting
+        run_to_block(20);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2),  move_1_hash, move_2));
+        assert_ok!(RoPaSci::reveal(Origin::signed(4), move_3_hash, move_3));
+        assert_ok!(RoPaSci::reveal(Origin::signed(3),  move_3_hash, move_4));
+
+        // winners get the money pool without the rake, half of the rake goes to the prize pool
+        assert_eq!(140, Balances::free_balance(2));
+        assert_eq!(10, SeasonPrizePool::<Test>::get());
+        assert_eq!(vec![(2, 40), (3, 40)], Leaderboard::<Test>::get().into_inner());
+
+        // end season
+        run_to_block(1000);
+
+        assert!(ropasci_events().contains(&crate::Event::SeasonEnded { season: 0, prizes: vec![(2, 6), (3, 3)] }));
+        assert_eq!(146, Balances::free_balance(2));
+        assert_eq!(143, Balances::free_balance(3));
+        assert_eq!(1, SeasonPrizePool::<Test>::get());
+        assert_eq!(1, CurrentSeason::<Test>::get());
+        assert!(Leaderboard::<Test>::get().is_empty());
+    });
+}
+
+#[test]
+fn clears_past_season_profits() {
+    build_and_execute(|| {
+        SeasonProfits::<Test>::insert(0, 1, 30);
+        SeasonProfits::<Test>::insert(0, 2, 5);
+        SeasonProfits::<Test>::insert(0, 3, -10);
+        Leaderboard::<Test>::put(BoundedVec::try_from(vec![(1, 30), (2, 5)]).unwrap());
+        SeasonProfits::<Test>::insert(1, 2, 5);
+
+        // end season
+        run_to_block(1000);
+
+        assert!(ropasci_events().contains(&crate::Event::SeasonEnded { season: 0, prizes: vec![(1, 0), (2, 0)] }));
+        assert!(Leaderboard::<Test>::get().is_empty());
+        // at most two profits of the past season are removed a block
+        assert_eq!(1, SeasonProfits::<Test>::iter_prefix(0).count());
+
+        run_to_block(1002);
+
+        assert_eq!(0, SeasonProfits::<Test>::iter_prefix(0).count());
+        assert_eq!(1, ClearedSeasons::<Test>::get());
+        assert_eq!(5, SeasonProfits::<Test>::get(1, 2));
+    });
+}
+
+#[test]
+fn notifies_game_ended() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let move_1 = vec![Hand::Scissors as u8];
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(b"misses reveal");
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash));
+
+        // end betting
+        run_to_block(20);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert!(EndedGames::get().is_empty());
+
+        // end game
+        run_to_block(40);
+
+        let mut ended_games = EndedGames::get();
+        assert_eq!(1, ended_games.len());
+        let (game_id, mut players) = ended_games.remove(0);
+        players.sort_by_key(|(player, _, _)| *player);
+        assert_eq!(move_1_hash, game_id);
+        assert_eq!(vec![(1, Some(Hand::Scissors), 2 * bet), (2, None, 0)], players);
+    });
+}
+
+#[test]
+fn can_set_config() {
+    build_and_execute(|| {
+        let config = GameConfig { min_round_length: 5, max_bet: 20, max_players: 2, ..Default::default() };
+
+        assert_noop!(RoPaSci::set_config(Origin::signed(1), config.clone()), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(
+            RoPaSci::set_config(Origin::root(), GameConfig { max_players: 1, ..config.clone() }),
+            Error::<Test>::ConfigInvalid,
+        );
+        assert_noop!(
+            RoPaSci::set_config(Origin::root(), GameConfig { max_players: MaxPlayers::get() + 1, ..config.clone() }),
+            Error::<Test>::ConfigInvalid,
+        );
+        // the rake and the jackpot share cannot take more than the whole pot
+        JackpotShare::set(Permill::from_percent(40));
+        assert_noop!(
+            RoPaSci::set_config(Origin::root(), GameConfig { rake: Permill::from_percent(61), ..config.clone() }),
+            Error::<Test>::ConfigInvalid,
+        );
+        assert_ok!(RoPaSci::set_config(Origin::root(), GameConfig { rake: Permill::from_percent(60), ..config.clone() }));
+
+        assert_ok!(RoPaSci::set_config(Origin::root(), config.clone()));
+        assert_eq!(config, PalletConfig::<Test>::get());
+    });
+}
+
+#[test]
+fn fail_config_limits() {
+    build_and_execute(|| {
+        let config = GameConfig { min_round_length: 5, max_bet: 20, max_players: 2, ..Default::default() };
+        assert_ok!(RoPaSci::set_config(Origin::root(), config));
+
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+
+        assert_noop!(
+            RoPaSci::start(Origin::signed(1), 4, 10, move_hash),
+            Error::<Test>::RoundLengthInvalid,
+        );
+        assert_noop!(
+            RoPaSci::start(Origin::signed(1), 5, 21, move_hash),
+            Error::<Test>::BetInvalid,
+        );
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), 5, 10, move_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_hash, move_hash));
+        assert_noop!(
+            RoPaSci::join(Origin::signed(3),  move_hash, move_hash),
+            Error::<Test>::GameFull,
+        );
+    });
+}
+
+#[test]
+fn can_pause() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let move_1 = vec![Hand::Rock as u8];
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(b"move");
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_noop!(RoPaSci::pause(Origin::signed(1)), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(RoPaSci::pause(Origin::root()));
+
+        assert_noop!(
+            RoPaSci::start(Origin::signed(2), round_length, bet, move_2_hash),
+            Error::<Test>::GamesPaused,
+        );
+        assert_noop!(
+            RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash),
+            Error::<Test>::GamesPaused,
+        );
+
+        // games in progress can still be finished
+        run_to_block(20);
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+
+        assert_ok!(RoPaSci::unpause(Origin::root()));
+        assert_ok!(RoPaSci::start(Origin::signed(2), round_length, bet, move_2_hash));
+    });
+}
+
+#[test]
+fn can_force_end() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let move_1 = vec![Hand::Rock as u8];
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(b"misses reveal");
+        let balance_1 = Balances::free_balance(1);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash));
+
+        // end betting
+        run_to_block(20);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::force_end(Origin::root(), move_1_hash));
+
+        assert!(!Games::<Test>::contains_key(move_1_hash));
+        assert!(!RevealingGamesIndex::<Test>::contains_key(40));
+        assert_eq!(balance_1 + bet, Balances::free_balance(1));
+        assert_noop!(RoPaSci::force_end(Origin::root(), move_1_hash), Error::<Test>::GameMissing);
+    });
+}
+
+#[test]
+fn can_force_refund() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+        let (balance_1, balance_2) = (Balances::free_balance(1), Balances::free_balance(2));
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_hash, move_hash));
+        assert_ok!(RoPaSci::force_refund(Origin::root(), move_hash));
+
+        assert_eq!((balance_1, balance_2), (Balances::free_balance(1), Balances::free_balance(2)));
+        assert!(!Games::<Test>::contains_key(move_hash));
+        assert_eq!(0, Moves::<Test>::iter_prefix(move_hash).count());
+        assert!(!BettingGamesIndex::<Test>::contains_key(20));
+        assert_eq!(0, Escrow::<Test>::get());
+    });
+}
+
+#[test]
+fn can_start_genesis_games() {
+    let move_1 = vec![Hand::Rock as u8];
+    let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+    let move_2 = vec![Hand::Paper as u8];
+    let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+    let genesis = crate::GenesisConfig::<Test> {
+        config: Default::default(),
+        games: vec![(1, move_1_hash, 10, 20, vec![(2, move_2_hash)])],
+    };
+
+    new_test_ext_with(genesis).execute_with(|| {
+        assert_eq!(80, Balances::free_balance(1));
+        assert_eq!(80, Balances::free_balance(2));
+        assert_eq!(40, Escrow::<Test>::get());
+        assert!(matches!(
+            Games::<Test>::get(move_1_hash),
+            Some(Game { stage: GameStage::Betting { participating_players: 2 }, .. })
+        ));
+        assert_eq!(Some(MoveStatus::Committed), RoPaSci::player_move_status(move_1_hash, 2));
+
+        // end betting
+        run_to_block(10);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+        assert_eq!(120, Balances::free_balance(2));
+        RoPaSci::do_try_state().unwrap();
+    });
+}
+
+#[test]
+#[should_panic(expected = "Genesis game round length is invalid")]
+fn fail_genesis_games_round_length() {
+    let genesis = crate::GenesisConfig::<Test> {
+        config: Default::default(),
+        games: vec![(1, <Test as Config>::MoveHasher::hash(b"move"), 0, 10, vec![])],
+    };
+
+    new_test_ext_with(genesis);
+}
+
+#[test]
+fn releases_game_deposit() {
+    build_and_execute(|| {
+        GameDeposit::set(5);
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let move_1 = vec![Hand::Rock as u8];
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_eq!(5, Balances::reserved_balance(1));
+        assert_eq!(Some((1, 5)), GameDeposits::<Test>::get(move_1_hash));
+        assert!(ropasci_events().contains(&crate::Event::GameStarted {
+            game_id: move_1_hash,
+            owner: 1,
+            round_length,
+            bet,
+            asset: None,
+            deposit: 5,
+            schedule: None,
+        }));
+
+        // end betting
+        run_to_block(21);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_eq!(0, Balances::reserved_balance(1));
+        assert_eq!(100, Balances::free_balance(1));
+        assert!(!GameDeposits::<Test>::contains_key(move_1_hash));
+        assert!(ropasci_events().contains(
+            &crate::Event::DepositReleased { game_id: move_1_hash, owner: 1, amount: 5 }
+        ));
+    });
+}
+
+#[test]
+fn slashes_game_deposit() {
+    build_and_execute(|| {
+        GameDeposit::set(5);
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let move_1_hash = <Test as Config>::MoveHasher::hash(b"never revealed");
+        let move_2 = vec![Hand::Rock as u8];
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2),  move_1_hash, move_2_hash));
+
+        // end betting
+        run_to_block(21);
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+
+        // end game
+        run_to_block(41);
+
+        assert_eq!(0, Balances::reserved_balance(1));
+        assert_eq!(100 - bet - 5, Balances::free_balance(1));
+        assert!(!GameDeposits::<Test>::contains_key(move_1_hash));
+        assert!(ropasci_events().contains(
+            &crate::Event::DepositSlashed { game_id: move_1_hash, owner: 1, amount: 5 }
+        ));
+    });
+}
+
+#[test]
+fn fail_game_deposit_not_enough() {
+    build_and_execute(|| {
+        GameDeposit::set(95);
+
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+        assert_noop!(
+            RoPaSci::start(Origin::signed(1), 20, 10, move_hash),
+            Error::<Test>::MoneyNotEnough,
+        );
+    });
+}
+
+#[test]
+fn limits_active_games() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let hash = |seed: &[u8]| <Test as Config>::MoveHasher::hash(seed);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, hash(b"game 1")));
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, hash(b"game 2")));
+        assert_ok!(RoPaSci::start(Origin::signed(2), round_length, bet, hash(b"game 3")));
+        assert_ok!(RoPaSci::join(Origin::signed(1), hash(b"game 3"), hash(b"move")));
+        assert_eq!(3, ActiveGames::<Test>::get(1));
+
+        assert_noop!(
+            RoPaSci::start(Origin::signed(1), round_length, bet, hash(b"game 4")),
+            Error::<Test>::TooManyActiveGames,
+        );
+        assert_ok!(RoPaSci::start(Origin::signed(2), round_length, bet, hash(b"game 4")));
+        assert_noop!(
+            RoPaSci::join(Origin::signed(1), hash(b"game 4"), hash(b"move")),
+            Error::<Test>::TooManyActiveGames,
+        );
+
+        // end all the games
+        run_to_block(40);
+
+        assert_eq!(0, ActiveGames::<Test>::get(1));
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, hash(b"game 5")));
+    });
+}
+
+#[test]
+fn can_self_exclude() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let move_1 = vec![Hand::Rock as u8];
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(b"move");
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::self_exclude(Origin::signed(1), 50));
+        assert_ok!(RoPaSci::self_exclude(Origin::signed(2), 50));
+        assert_noop!(RoPaSci::self_exclude(Origin::signed(1), 30), Error::<Test>::SelfExclusionTooShort);
+
+        assert_noop!(
+            RoPaSci::start(Origin::signed(1), round_length, bet, move_2_hash),
+            Error::<Test>::PlayerSelfExcluded,
+        );
+        assert_noop!(
+            RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash),
+            Error::<Test>::PlayerSelfExcluded,
+        );
+
+        // games already joined can be finished
+        run_to_block(20);
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+
+        run_to_block(50);
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_2_hash));
+    });
+}
+
+#[test]
+fn limits_spending() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let hash = |seed: &[u8]| <Test as Config>::MoveHasher::hash(seed);
+
+        assert_ok!(RoPaSci::set_spending_cap(Origin::signed(1), Some(25)));
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, 10, hash(b"game 1")));
+        assert_ok!(RoPaSci::start(Origin::signed(2), round_length, 10, hash(b"game 2")));
+        assert_ok!(RoPaSci::join(Origin::signed(1), hash(b"game 2"), hash(b"move")));
+        assert_noop!(
+            RoPaSci::start(Origin::signed(1), round_length, 10, hash(b"game 3")),
+            Error::<Test>::SpendingCapExceeded,
+        );
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, 5, hash(b"game 3")));
+
+        // the cap applies per spending period
+        run_to_block(100);
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, 10, hash(b"game 4")));
+
+        assert_ok!(RoPaSci::set_spending_cap(Origin::signed(1), None));
+        assert_eq!(Some((1, 10)), Spendings::<Test>::get(1));
+    });
+}
+
+#[test]
+fn delays_raising_spending_cap() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let hash = |seed: &[u8]| <Test as Config>::MoveHasher::hash(seed);
+
+        assert_ok!(RoPaSci::set_spending_cap(Origin::signed(1), Some(20)));
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, 20, hash(b"game 1")));
+
+        // neither removing nor raising the cap resets the spending of the period
+        assert_ok!(RoPaSci::set_spending_cap(Origin::signed(1), None));
+        assert_eq!(
+            ropasci_events().last(),
+            Some(&crate::Event::SpendingCapPending { player: 1, cap: None, applies_at: 100 }),
+        );
+        assert_noop!(
+            RoPaSci::start(Origin::signed(1), round_length, 10, hash(b"game 2")),
+            Error::<Test>::SpendingCapExceeded,
+        );
+        assert_ok!(RoPaSci::set_spending_cap(Origin::signed(1), Some(50)));
+        assert_noop!(
+            RoPaSci::start(Origin::signed(1), round_length, 10, hash(b"game 2")),
+            Error::<Test>::SpendingCapExceeded,
+        );
+        assert_eq!(Some((0, 20)), Spendings::<Test>::get(1));
+
+        // lowering the cap applies immediately
+        assert_ok!(RoPaSci::set_spending_cap(Origin::signed(1), Some(15)));
+        assert_eq!(Some(15), SpendingCaps::<Test>::get(1));
+        assert!(!PendingSpendingCaps::<Test>::contains_key(1));
+
+        // the raised cap applies from the next spending period
+        assert_ok!(RoPaSci::set_spending_cap(Origin::signed(1), Some(30)));
+        run_to_block(100);
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, 30, hash(b"game 2")));
+    });
+}
+
+#[test]
+fn can_play_for_asset() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 30;
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+        let balance = Balances::free_balance(1);
+
+        assert_ok!(RoPaSci::start_with_asset(Origin::signed(1), round_length, bet, ASSET, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash));
+        assert_eq!(70, Assets::balance(ASSET, 1));
+        assert_eq!(2 * bet, Assets::balance(ASSET, RoPaSci::account_id()));
+        assert_eq!(balance, Balances::free_balance(1));
+        assert_eq!(0, Escrow::<Test>::get());
+        assert!(ropasci_events().contains(&crate::Event::GameStarted {
+            game_id: move_1_hash,
+            owner: 1,
+            round_length,
+            bet,
+            asset: Some(ASSET),
+            deposit: 0,
+            schedule: None,
+        }));
+        assert!(RoPaSci::game_updates().contains(&GameUpdate::GameStarted {
+            game_id: move_1_hash,
+            owner: 1,
+            round_length,
+            bet,
+            asset: Some(ASSET),
+            deposit: 0,
+            schedule: None,
+        }));
+
+        // end betting
+        run_to_block(21);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+
+        assert_eq!(70, Assets::balance(ASSET, 1));
+        assert_eq!(130, Assets::balance(ASSET, 2));
+        assert_eq!(0, Assets::balance(ASSET, RoPaSci::account_id()));
+        assert!(!GameAssets::<Test>::contains_key(move_1_hash));
+        // asset games do not count towards the profits
+        assert_eq!(0, RoPaSci::player_stats(2).net_profit);
+        assert_eq!(1, RoPaSci::player_stats(2).wins);
+    });
+}
+
+#[test]
+fn fail_asset_bet() {
+    build_and_execute(|| {
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+
+        assert_noop!(
+            RoPaSci::start_with_asset(Origin::signed(1), 20, 0, ASSET, move_hash),
+            Error::<Test>::BetInvalid,
+        );
+        assert_noop!(
+            RoPaSci::start_with_asset(Origin::signed(4), 20, 10, ASSET, move_hash),
+            Error::<Test>::MoneyNotEnough,
+        );
+
+        assert_ok!(RoPaSci::start_with_asset(Origin::signed(1), 20, 10, ASSET, move_hash));
+        assert_noop!(
+            RoPaSci::join(Origin::signed(4), move_hash, move_hash),
+            Error::<Test>::MoneyNotEnough,
+        );
+    });
+}
+
+#[test]
+fn keeps_failed_payout_for_claim() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+
+        assert_ok!(RoPaSci::start_with_asset(Origin::signed(1), 20, 30, ASSET, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash));
+
+        // end betting
+        run_to_block(21);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(Assets::freeze_asset(Origin::signed(5), ASSET));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+
+        assert_eq!(70, Assets::balance(ASSET, 2));
+        assert_eq!(60, UnclaimedPayouts::<Test>::get(2, Some(ASSET)));
+        assert!(ropasci_events().contains(&crate::Event::PayoutUnclaimed {
+            player: 2,
+            asset: Some(ASSET),
+            amount: 60,
+        }));
+        assert_noop!(
+            RoPaSci::claim_payout(Origin::signed(2), Some(ASSET)),
+            pallet_assets::Error::<Test>::Frozen,
+        );
+        assert_noop!(RoPaSci::claim_payout(Origin::signed(2), None), Error::<Test>::PayoutMissing);
+
+        assert_ok!(Assets::thaw_asset(Origin::signed(5), ASSET));
+        assert_ok!(RoPaSci::claim_payout(Origin::signed(2), Some(ASSET)));
+
+        assert_eq!(130, Assets::balance(ASSET, 2));
+        assert!(!UnclaimedPayouts::<Test>::contains_key(2, Some(ASSET)));
+        assert_eq!(
+            Some(crate::Event::PayoutClaimed { player: 2, asset: Some(ASSET), amount: 60 }),
+            ropasci_events().pop(),
+        );
+    });
+}
+
+#[test]
+fn withdraws_asset_rake() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let config = GameConfig { rake: Permill::from_percent(10), ..Default::default() };
+        assert_ok!(RoPaSci::set_config(Origin::root(), config));
+
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+
+        assert_ok!(RoPaSci::start_with_asset(Origin::signed(1), 20, 30, ASSET, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash));
+
+        // end betting
+        run_to_block(21);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+
+        assert_eq!(124, Assets::balance(ASSET, 2));
+        assert_eq!(6, AssetRakes::<Test>::get(ASSET));
+        assert_eq!(6, Assets::balance(ASSET, RoPaSci::account_id()));
+
+        assert_noop!(
+            RoPaSci::withdraw_asset_rake(Origin::signed(1), ASSET, 4),
+            sp_runtime::DispatchError::BadOrigin,
+        );
+        assert_ok!(RoPaSci::withdraw_asset_rake(Origin::root(), ASSET, 4));
+        assert_noop!(RoPaSci::withdraw_asset_rake(Origin::root(), ASSET, 4), Error::<Test>::RakeMissing);
+
+        assert_eq!(6, Assets::balance(ASSET, 4));
+        assert_eq!(0, Assets::balance(ASSET, RoPaSci::account_id()));
+        assert_eq!(
+            Some(crate::Event::AssetRakeWithdrawn { asset: ASSET, dest: 4, amount: 6 }),
+            ropasci_events().pop(),
+        );
+    });
+}
+
+#[test]
+fn asset_bets_are_not_capped() {
+    build_and_execute(|| {
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+
+        assert_ok!(RoPaSci::set_spending_cap(Origin::signed(1), Some(5)));
+        assert_ok!(RoPaSci::start_with_asset(Origin::signed(1), 20, 30, ASSET, move_hash));
+        assert_eq!(None, Spendings::<Test>::get(1));
+    });
+}
+
+fn mint_prize(owner: u64) -> (u32, u32) {
+    let (collection, item) = (0, 42);
+    assert_ok!(Uniques::create(Origin::signed(owner), collection, owner));
+    assert_ok!(Uniques::mint(Origin::signed(owner), collection, item, owner));
+    (collection, item)
+}
+
+#[test]
+fn awards_prize() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let move_1 = vec![Hand::Rock as u8];
+        let (move_2, move_3) = (vec![Hand::Paper as u8], vec![Hand::Paper as u8, 1]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+        let move_3_hash = <Test as Config>::MoveHasher::hash(&move_3);
+        let (collection, item) = mint_prize(1);
+        let balance_1 = Balances::free_balance(1);
+
+        assert_noop!(
+            RoPaSci::start_with_prize(Origin::signed(2), round_length, bet, collection, item, move_2_hash),
+            Error::<Test>::PrizeNotOwned,
+        );
+        assert_ok!(RoPaSci::start_with_prize(Origin::signed(1), round_length, bet, collection, item, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(3), move_1_hash, move_3_hash));
+        assert_eq!(balance_1, Balances::free_balance(1));
+        assert_eq!(Some(RoPaSci::account_id()), Uniques::owner(collection, item));
+        assert_eq!(2 * bet, Escrow::<Test>::get());
+        // the owner does not bet and the prize games take no part in the jackpot
+        assert_eq!(Some(bet), RoPaSci::payout_quote(move_1_hash, 2));
+
+        // end betting
+        run_to_block(21);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+        assert_ok!(RoPaSci::reveal(Origin::signed(3), move_1_hash, move_3));
+
+        // the winners split the bets, a single one of them gets the prize
+        assert_eq!(100, Balances::free_balance(2));
+        assert_eq!(100, Balances::free_balance(3));
+        let winner = Uniques::owner(collection, item).unwrap();
+        assert!(winner == 2 || winner == 3);
+        assert!(ropasci_events().contains(
+            &crate::Event::PrizeAwarded { game_id: move_1_hash, winner, collection, item }
+        ));
+        assert!(!GamePrizes::<Test>::contains_key(move_1_hash));
+    });
+}
+
+#[test]
+fn keeps_failed_prize_for_claim() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+        let (collection, item) = mint_prize(1);
+
+        assert_ok!(RoPaSci::start_with_prize(Origin::signed(1), round_length, bet, collection, item, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash));
+
+        // end betting
+        run_to_block(21);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(Uniques::freeze(Origin::signed(1), collection, item));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+
+        assert_eq!(Some(RoPaSci::account_id()), Uniques::owner(collection, item));
+        assert_eq!(Some((2, collection, item)), GamePrizes::<Test>::get(move_1_hash));
+        let events = ropasci_events();
+        assert!(events.contains(
+            &crate::Event::PrizeUnclaimed { game_id: move_1_hash, player: 2, collection, item }
+        ));
+        assert!(!events.iter().any(|event| matches!(event, crate::Event::PrizeAwarded { .. })));
+        // the id of the unclaimed prize cannot be reused
+        assert_noop!(
+            RoPaSci::start(Origin::signed(3), round_length, bet, move_1_hash),
+            Error::<Test>::GameExists,
+        );
+
+        assert_noop!(RoPaSci::claim_prize(Origin::signed(2), move_1_hash), pallet_uniques::Error::<Test>::Frozen);
+        assert_noop!(RoPaSci::claim_prize(Origin::signed(1), move_1_hash), Error::<Test>::PrizeMissing);
+
+        assert_ok!(Uniques::thaw(Origin::signed(1), collection, item));
+        assert_ok!(RoPaSci::claim_prize(Origin::signed(2), move_1_hash));
+
+        assert_eq!(Some(2), Uniques::owner(collection, item));
+        assert!(!GamePrizes::<Test>::contains_key(move_1_hash));
+        assert_eq!(
+            Some(crate::Event::PrizeClaimed { game_id: move_1_hash, player: 2, collection, item }),
+            ropasci_events().pop(),
+        );
+    });
+}
+
+#[test]
+fn returns_prize() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let move_hash = <Test as Config>::MoveHasher::hash(b"move");
+        let (collection, item) = mint_prize(1);
+
+        assert_ok!(RoPaSci::start_with_prize(Origin::signed(1), round_length, bet, collection, item, move_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_hash, move_hash));
+
+        // end game without reveals
+        run_to_block(40);
+
+        assert_eq!(Some(1), Uniques::owner(collection, item));
+        assert_eq!(100, Balances::free_balance(1));
+        assert_eq!(100, Balances::free_balance(2));
+    });
+}
+
+#[test]
+fn breaks_tie_randomly() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let move_1 = vec![Hand::Rock as u8];
+        let (move_2, move_3) = (vec![Hand::Paper as u8], vec![Hand::Paper as u8, 1]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+        let move_3_hash = <Test as Config>::MoveHasher::hash(&move_3);
+        let seed = H256::repeat_byte(7);
+        RandomSeed::set(seed);
+
+        assert_noop!(
+            RoPaSci::set_tie_break(Origin::signed(1), move_1_hash, TieBreak::Random),
+            Error::<Test>::GameMissing,
+        );
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_noop!(
+            RoPaSci::set_tie_break(Origin::signed(2), move_1_hash, TieBreak::Random),
+            Error::<Test>::NotGameOwner,
+        );
+        assert_ok!(RoPaSci::set_tie_break(Origin::signed(1), move_1_hash, TieBreak::Random));
+        assert_eq!(Some(TieBreak::Random), GameTieBreaks::<Test>::get(move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash));
+        assert_noop!(
+            RoPaSci::set_tie_break(Origin::signed(1), move_1_hash, TieBreak::Split),
+            Error::<Test>::GameAlreadyJoined,
+        );
+        assert_ok!(RoPaSci::join(Origin::signed(3), move_1_hash, move_3_hash));
+
+        // end betting
+        run_to_block(21);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+        assert_ok!(RoPaSci::reveal(Origin::signed(3), move_1_hash, move_3));
+
+        // the tie is drawn after the deadline once the randomness was not known before it
+        assert!(Games::<Test>::contains_key(move_1_hash));
+        run_to_block(41);
+        assert!(Games::<Test>::contains_key(move_1_hash));
+        assert_noop!(
+            RoPaSci::reveal(Origin::signed(1), move_1_hash, vec![Hand::Rock as u8]),
+            Error::<Test>::GameWrongStage,
+        );
+        run_to_block(42);
+
+        // both paper players tie, the one at the odd index of the seed takes all the bets
+        let events = ropasci_events();
+        assert!(events.contains(&crate::Event::TieBroken {
+            game_id: move_1_hash,
+            seed,
+            tied: vec![3, 2],
+            winner: 2,
+        }));
+        assert!(matches!(
+            events.last(),
+            Some(crate::Event::GameEnded { winners, reward: 30, .. }) if *winners == vec![2]
+        ));
+        assert_eq!(100 - bet + 3 * bet, Balances::free_balance(2));
+        assert_eq!(100 - bet, Balances::free_balance(3));
+        assert!(!GameTieBreaks::<Test>::contains_key(move_1_hash));
+    });
+}
+
+// Plays a game of the given players and hands to the end, the first player starts it.
+fn play_game(bet: u64, rollover: bool, hands: &[(u64, Hand)]) -> H256 {
+    let round_length = 20;
+    let start = System::block_number();
+    let reveals = hands.iter()
+        .map(|(player, hand)| (*player, vec![*hand as u8, *player as u8, start as u8]))
+        .collect::<Vec<_>>();
+    let (owner, owner_reveal) = &reveals[0];
+    let game_id = <Test as Config>::MoveHasher::hash(owner_reveal);
+
+    assert_ok!(RoPaSci::start(Origin::signed(*owner), round_length, bet, game_id));
+    assert_ok!(RoPaSci::set_jackpot_rollover(Origin::signed(*owner), game_id, rollover));
+    for (player, reveal) in &reveals[1..] {
+        assert_ok!(RoPaSci::join(Origin::signed(*player), game_id, <Test as Config>::MoveHasher::hash(reveal)));
+    }
+
+    // end betting
+    run_to_block(start + round_length);
+
+    for (player, reveal) in reveals {
+        assert_ok!(RoPaSci::reveal(Origin::signed(player), game_id, reveal));
+    }
+    game_id
+}
+
+#[test]
+fn feeds_and_pays_jackpot() {
+    build_and_execute(|| {
+        run_to_block(1);
+        JackpotShare::set(Permill::from_percent(10));
+
+        let bet = 10;
+        let asset_game_id = <Test as Config>::MoveHasher::hash(b"asset");
+        assert_ok!(RoPaSci::start_with_asset(Origin::signed(1), 20, bet, ASSET, asset_game_id));
+        assert_noop!(
+            RoPaSci::set_jackpot_rollover(Origin::signed(1), asset_game_id, true),
+            Error::<Test>::JackpotUnavailable,
+        );
+        assert_ok!(RoPaSci::force_refund(Origin::root(), asset_game_id));
+
+        // a duel feeds the jackpot but is too small to win it
+        let game_id = play_game(bet, false, &[(1, Hand::Rock), (2, Hand::Paper)]);
+        assert_eq!(2, RoPaSci::jackpot());
+        assert_eq!(100 - bet + 18, Balances::free_balance(2));
+        assert!(ropasci_events().contains(&crate::Event::JackpotIncreased { game_id, amount: 2, jackpot: 2 }));
+
+        // the whole pot of a drawn game rolls over
+        let game_id = play_game(bet, true, &[(1, Hand::Rock), (2, Hand::Rock)]);
+        assert_eq!(22, RoPaSci::jackpot());
+        assert_eq!(100 - 2 * bet, Balances::free_balance(1));
+        assert!(!ropasci_events().iter().any(|event| matches!(
+            event,
+            crate::Event::Refunded { game_id: refunded, .. } if *refunded == game_id
+        )));
+
+        // a sole winner of three players takes the jackpot
+        let game_id = play_game(bet, false, &[(1, Hand::Rock), (2, Hand::Scissors), (3, Hand::Scissors)]);
+        assert_eq!(0, RoPaSci::jackpot());
+        assert_eq!(100 - 3 * bet + 27 + 25, Balances::free_balance(1));
+        assert_eq!(Some(&crate::Event::JackpotWon { game_id, winner: 1, amount: 25 }), ropasci_events().iter().rev().nth(1));
+    });
+}
+
+#[test]
+fn tie_break_winner_does_not_win_jackpot() {
+    build_and_execute(|| {
+        run_to_block(1);
+        JackpotShare::set(Permill::from_percent(10));
+        Jackpot::<Test>::put(50);
+        Balances::make_free_balance_be(&RoPaSci::account_id(), 51);
+
+        let bet = 10;
+        let move_1 = vec![Hand::Rock as u8];
+        let (move_2, move_3) = (vec![Hand::Paper as u8], vec![Hand::Paper as u8, 1]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+        let move_3_hash = <Test as Config>::MoveHasher::hash(&move_3);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), 20, bet, move_1_hash));
+        assert_ok!(RoPaSci::set_tie_break(Origin::signed(1), move_1_hash, TieBreak::Random));
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(3), move_1_hash, move_3_hash));
+
+        // end betting
+        run_to_block(21);
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+        assert_ok!(RoPaSci::reveal(Origin::signed(3), move_1_hash, move_3));
+
+        // draw the tie after the deadline
+        run_to_block(42);
+
+        // the single winner of the tie-break was not the sole winner of the game
+        let events = ropasci_events();
+        assert!(events.iter().any(|event| matches!(event, crate::Event::TieBroken { .. })));
+        assert!(!events.iter().any(|event| matches!(event, crate::Event::JackpotWon { .. })));
+        assert_eq!(53, RoPaSci::jackpot());
+    });
+}
+
+#[test]
+fn settles_side_bets() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let bet = 10;
+        let move_1 = vec![Hand::Rock as u8];
+        let move_2 = vec![Hand::Paper as u8];
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+        let held = Balances::free_balance(RoPaSci::account_id());
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), 20, bet, move_1_hash));
+        assert_noop!(RoPaSci::side_bet(Origin::signed(3), move_1_hash, 2, 10), Error::<Test>::PlayerMoveMissing);
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash));
+        assert_noop!(RoPaSci::side_bet(Origin::signed(1), move_1_hash, 2, 10), Error::<Test>::SideBetConflict);
+        assert_noop!(RoPaSci::side_bet(Origin::signed(3), move_1_hash, 2, 0), Error::<Test>::BetInvalid);
+
+        assert_ok!(RoPaSci::side_bet(Origin::signed(3), move_1_hash, 2, 10));
+        assert_ok!(RoPaSci::side_bet(Origin::signed(4), move_1_hash, 2, 20));
+        assert_ok!(RoPaSci::side_bet(Origin::signed(5), move_1_hash, 1, 20));
+        assert_noop!(RoPaSci::side_bet(Origin::signed(3), move_1_hash, 1, 10), Error::<Test>::SideBetPlaced);
+        assert_noop!(RoPaSci::side_bet(Origin::signed(6), move_1_hash, 1, 10), Error::<Test>::TooManySideBets);
+        assert_eq!(3, SideBetCounts::<Test>::get(move_1_hash));
+        assert_noop!(RoPaSci::join(Origin::signed(3), move_1_hash, move_2_hash), Error::<Test>::SideBetConflict);
+        assert_eq!(2 * bet + 50, Escrow::<Test>::get());
+
+        // end betting
+        run_to_block(21);
+
+        assert_noop!(RoPaSci::side_bet(Origin::signed(3), move_1_hash, 1, 10), Error::<Test>::GameWrongStage);
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), move_1_hash, move_2));
+
+        // the backers of the paper player split all the stakes and the remainder goes to the jackpot
+        assert_eq!(100 - 10 + 16, Balances::free_balance(3));
+        assert_eq!(100 - 20 + 33, Balances::free_balance(4));
+        assert_eq!(100 - 20, Balances::free_balance(5));
+        assert_eq!(0, Escrow::<Test>::get());
+        assert!(!SideBetCounts::<Test>::contains_key(move_1_hash));
+        assert!(ropasci_events().contains(&crate::Event::SideBetsSettled {
+            game_id: move_1_hash,
+            payouts: vec![(3, 16), (4, 33), (5, 0)],
+        }));
+        assert!(ropasci_events().contains(&crate::Event::JackpotIncreased {
+            game_id: move_1_hash,
+            amount: 1,
+            jackpot: Jackpot::<Test>::get(),
+        }));
+        // nothing but the jackpot is left behind in the pallet account
+        assert_eq!(held + Jackpot::<Test>::get(), Balances::free_balance(RoPaSci::account_id()));
+    });
+}
+
+#[test]
+fn can_play_team_game() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let reveals = [
+            (1, Team::First, vec![Hand::Rock as u8, 1]),
+            (2, Team::First, vec![Hand::Paper as u8, 2]),
+            (3, Team::Second, vec![Hand::Rock as u8, 3]),
+            (4, Team::Second, vec![Hand::Rock as u8, 4]),
+        ];
+        let hash = |reveal: &Vec<u8>| <Test as Config>::MoveHasher::hash(reveal);
+        let game_id = hash(&reveals[0].2);
+
+        assert_noop!(
+            RoPaSci::start_team(Origin::signed(1), round_length, bet, 1, game_id),
+            Error::<Test>::TeamSizeInvalid,
+        );
+        assert_ok!(RoPaSci::start_team(Origin::signed(1), round_length, bet, 2, game_id));
+        assert_noop!(
+            RoPaSci::set_tie_break(Origin::signed(1), game_id, TieBreak::Random),
+            Error::<Test>::TieBreakUnavailable,
+        );
+        assert_noop!(RoPaSci::join(Origin::signed(2), game_id, hash(&reveals[1].2)), Error::<Test>::TeamRequired);
+        for (player, team, reveal) in &reveals[1..] {
+            assert_ok!(RoPaSci::join_team(Origin::signed(*player), game_id, *team, hash(reveal)));
+        }
+        assert_noop!(
+            RoPaSci::join_team(Origin::signed(5), game_id, Team::Second, hash(b"move")),
+            Error::<Test>::TeamFull,
+        );
+
+        // end betting
+        run_to_block(21);
+
+        for (player, _, reveal) in reveals {
+            assert_ok!(RoPaSci::reveal(Origin::signed(player), game_id, reveal));
+        }
+
+        // the rock and paper tie of the first team is paper which beats the rock of the second one
+        assert_eq!(100 - bet + 2 * bet, Balances::free_balance(1));
+        assert_eq!(100 - bet + 2 * bet, Balances::free_balance(2));
+        assert_eq!(100 - bet, Balances::free_balance(3));
+        assert_eq!(100 - bet, Balances::free_balance(4));
+        assert!(!TeamGames::<Test>::contains_key(game_id));
+        assert_eq!(0, TeamMembers::<Test>::iter_prefix(game_id).count());
+    });
+}
+
+#[test]
+fn refunds_incomplete_team_game() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let hash = |reveal: &[u8]| <Test as Config>::MoveHasher::hash(reveal);
+        let game_id = hash(&[Hand::Rock as u8, 1]);
+
+        assert_ok!(RoPaSci::start_team(Origin::signed(1), round_length, bet, 2, game_id));
+        assert_ok!(RoPaSci::join_team(Origin::signed(2), game_id, Team::First, hash(&[Hand::Paper as u8, 2])));
+        assert_ok!(RoPaSci::join_team(Origin::signed(3), game_id, Team::Second, hash(&[Hand::Rock as u8, 3])));
+
+        // the second team is one player short when betting ends
+        run_to_block(21);
+
+        assert_eq!(Some(&crate::Event::Refunded { game_id, players: vec![1, 2, 3], amount: bet }),
+            ropasci_events().last());
+        for player in 1..=3 {
+            assert_eq!(100, Balances::free_balance(player));
+        }
+        assert!(Games::<Test>::get(game_id).is_none());
+        assert!(!TeamGames::<Test>::contains_key(game_id));
+        assert_eq!(0, TeamMembers::<Test>::iter_prefix(game_id).count());
+        assert_eq!(0, Escrow::<Test>::get());
+    });
+}
+
+#[test]
+fn decides_team_hand_by_majority() {
+    assert_eq!(None, Hand::majority(&[]));
+    assert_eq!(Some(Hand::Rock), Hand::majority(&[Hand::Rock, Hand::Rock, Hand::Paper]));
+    assert_eq!(Some(Hand::Paper), Hand::majority(&[Hand::Rock, Hand::Paper]));
+    assert_eq!(Some(Hand::Rock), Hand::majority(&[Hand::Scissors, Hand::Rock]));
+    assert_eq!(None, Hand::majority(&[Hand::Rock, Hand::Paper, Hand::Scissors]));
+}
+
+#[test]
+fn settles_house_games() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let bet = 10;
+        let win_payout = 19;
+        let (move_1, move_2, move_3) = (vec![Hand::Paper as u8], vec![Hand::Rock as u8], vec![Hand::Scissors as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+        let move_3_hash = <Test as Config>::MoveHasher::hash(&move_3);
+        let house = RoPaSci::house_account();
+
+        assert_noop!(RoPaSci::play_house(Origin::signed(1), bet, move_1_hash), Error::<Test>::HouseBankrollLow);
+
+        Balances::make_free_balance_be(&house, 100);
+        assert_ok!(RoPaSci::play_house(Origin::signed(1), bet, move_1_hash));
+        assert_ok!(RoPaSci::play_house(Origin::signed(2), bet, move_2_hash));
+        assert_ok!(RoPaSci::play_house(Origin::signed(3), bet, move_3_hash));
+        assert_noop!(RoPaSci::play_house(Origin::signed(4), bet, move_1_hash), Error::<Test>::GameExists);
+        assert_eq!(130, Balances::free_balance(house));
+        assert_eq!(3 * win_payout, HouseLiability::<Test>::get());
+        assert_eq!(Some(&crate::Event::HouseGameStarted {
+            game_id: move_3_hash,
+            player: 3,
+            bet,
+            draw_at: 6,
+        }), ropasci_events().last());
+
+        assert_noop!(
+            RoPaSci::reveal_house(Origin::signed(1), move_1_hash, move_1.clone()),
+            Error::<Test>::HouseHandNotReady,
+        );
+
+        // the house draws rock
+        run_to_block(6);
+
+        assert!(ropasci_events().contains(&crate::Event::HouseHandDrawn { game_id: move_1_hash, deadline: 16 }));
+        assert_noop!(
+            RoPaSci::reveal_house(Origin::signed(2), move_1_hash, move_1.clone()),
+            Error::<Test>::PlayerMoveMissing,
+        );
+        assert_noop!(
+            RoPaSci::reveal_house(Origin::signed(1), move_1_hash, move_2.clone()),
+            Error::<Test>::PlayerRevealMismatch,
+        );
+
+        assert_ok!(RoPaSci::reveal_house(Origin::signed(1), move_1_hash, move_1));
+        assert_ok!(RoPaSci::reveal_house(Origin::signed(2), move_2_hash, move_2));
+        assert_ok!(RoPaSci::reveal_house(Origin::signed(3), move_3_hash, move_3));
+
+        assert_eq!(100 - bet + win_payout, Balances::free_balance(1));
+        assert_eq!(100, Balances::free_balance(2));
+        assert_eq!(100 - bet, Balances::free_balance(3));
+        assert_eq!(100 + bet - win_payout + bet, Balances::free_balance(house));
+        assert_eq!(0, HouseLiability::<Test>::get());
+        assert_eq!(0, HouseGames::<Test>::iter().count());
+        assert_eq!(0, ActiveGames::<Test>::iter().count());
+        assert_eq!(Some(&crate::Event::HouseGameEnded {
+            game_id: move_3_hash,
+            player: 3,
+            hand: Some(Hand::Scissors),
+            house_hand: Hand::Rock,
+            payout: 0,
+        }), ropasci_events().last());
+    });
+}
+
+#[test]
+fn forfeits_house_game() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let bet = 10;
+        let move_hash = <Test as Config>::MoveHasher::hash(&[Hand::Paper as u8]);
+        let house = RoPaSci::house_account();
+        RandomSeed::set(H256::repeat_byte(Hand::Scissors as u8));
+        Balances::make_free_balance_be(&house, 100);
+
+        assert_ok!(RoPaSci::play_house(Origin::signed(1), bet, move_hash));
+
+        run_to_block(6);
+
+        assert_eq!(Some(Hand::Scissors), HouseGames::<Test>::get(move_hash).and_then(|game| game.house_hand));
+
+        // the move is not revealed until the deadline
+        run_to_block(16);
+
+        assert_eq!(100 - bet, Balances::free_balance(1));
+        assert_eq!(100 + bet, Balances::free_balance(house));
+        assert_eq!(0, HouseLiability::<Test>::get());
+        assert_eq!(None, HouseGames::<Test>::get(move_hash));
+        assert_eq!(Some(&crate::Event::HouseGameEnded {
+            game_id: move_hash,
+            player: 1,
+            hand: None,
+            house_hand: Hand::Scissors,
+            payout: 0,
+        }), ropasci_events().last());
+    });
+}
+
+#[test]
+fn draws_house_hand_uniformly() {
+    // the bytes above the largest multiple of the hands count are skipped
+    assert_eq!(Some(Hand::Scissors), RoPaSci::draw_hand(&[u8::MAX, 254]));
+    assert_eq!(Some(Hand::Rock), RoPaSci::draw_hand(&[3, u8::MAX]));
+    assert_eq!(None, RoPaSci::draw_hand(&[u8::MAX; 32]));
+
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let move_hash = <Test as Config>::MoveHasher::hash(&[Hand::Paper as u8]);
+        let mut seed = [Hand::Scissors as u8; 32];
+        seed[0] = u8::MAX;
+        RandomSeed::set(H256(seed));
+        Balances::make_free_balance_be(&RoPaSci::house_account(), 100);
+
+        assert_ok!(RoPaSci::play_house(Origin::signed(1), 10, move_hash));
+
+        run_to_block(6);
+
+        assert_eq!(Some(Hand::Scissors), HouseGames::<Test>::get(move_hash).and_then(|game| game.house_hand));
+    });
+}
+
+#[test]
+fn funds_and_withdraws_house() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let house = RoPaSci::house_account();
+
+        assert_noop!(RoPaSci::fund_house(Origin::root(), 50), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(RoPaSci::fund_house(Origin::signed(1), 50));
+        assert_eq!(50, Balances::free_balance(house));
+        assert_eq!(50, Balances::free_balance(1));
+        assert_eq!(Some(crate::Event::HouseFunded { source: 1, amount: 50 }), ropasci_events().pop());
+
+        assert_ok!(RoPaSci::play_house(Origin::signed(2), 10, <Test as Config>::MoveHasher::hash(b"move")));
+        assert_eq!(19, HouseLiability::<Test>::get());
+
+        // the house keeps enough to cover the winnings of the game in progress
+        assert_noop!(RoPaSci::withdraw_house(Origin::signed(1), 3, 41), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(RoPaSci::withdraw_house(Origin::root(), 3, 42), Error::<Test>::HouseBankrollLow);
+        assert_ok!(RoPaSci::withdraw_house(Origin::root(), 3, 41));
+
+        assert_eq!(19, Balances::free_balance(house));
+        assert_eq!(141, Balances::free_balance(3));
+        assert_eq!(Some(crate::Event::HouseWithdrawn { dest: 3, amount: 41 }), ropasci_events().pop());
+    });
+}
+
+#[test]
+fn keeps_unpaid_house_winnings() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let move_reveal = vec![Hand::Paper as u8];
+        let move_hash = <Test as Config>::MoveHasher::hash(&move_reveal);
+        let house = RoPaSci::house_account();
+        Balances::make_free_balance_be(&house, 100);
+
+        assert_ok!(RoPaSci::play_house(Origin::signed(1), 10, move_hash));
+
+        // the house draws rock
+        run_to_block(6);
+
+        // the house cannot pay out the winnings
+        Balances::make_free_balance_be(&house, 5);
+        assert_ok!(RoPaSci::reveal_house(Origin::signed(1), move_hash, move_reveal));
+
+        assert_eq!(90, Balances::free_balance(1));
+        assert_eq!(19, HouseDebts::<Test>::get(1));
+        assert_eq!(19, HouseLiability::<Test>::get());
+        assert!(ropasci_events().contains(
+            &crate::Event::HouseDebtIncurred { game_id: move_hash, player: 1, amount: 19 }
+        ));
+        assert_noop!(
+            RoPaSci::claim_house_payout(Origin::signed(1)),
+            pallet_balances::Error::<Test>::InsufficientBalance,
+        );
+        assert_noop!(RoPaSci::claim_house_payout(Origin::signed(2)), Error::<Test>::HouseDebtMissing);
+
+        // the claim does not reap the house account
+        Balances::make_free_balance_be(&house, 19);
+        assert_noop!(RoPaSci::claim_house_payout(Origin::signed(1)), pallet_balances::Error::<Test>::KeepAlive);
+
+        assert_ok!(RoPaSci::fund_house(Origin::signed(2), 50));
+        assert_ok!(RoPaSci::claim_house_payout(Origin::signed(1)));
+
+        assert_eq!(109, Balances::free_balance(1));
+        assert_eq!(50, Balances::free_balance(house));
+        assert_eq!(0, HouseLiability::<Test>::get());
+        assert!(!HouseDebts::<Test>::contains_key(1));
+        assert_eq!(Some(crate::Event::HouseDebtPaid { player: 1, amount: 19 }), ropasci_events().pop());
+    });
+}
+
+#[test]
+fn can_play_challenge() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let stake = 10;
+        let (move_1, move_2) = (vec![Hand::Scissors as u8], vec![Hand::Rock as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+
+        assert_ok!(RoPaSci::post_challenge(Origin::signed(1), stake, move_1_hash));
+        assert_ok!(RoPaSci::post_challenge(Origin::signed(1), stake, move_2_hash));
+        assert_noop!(RoPaSci::post_challenge(Origin::signed(2), stake, move_1_hash), Error::<Test>::GameExists);
+        assert_noop!(RoPaSci::cancel_challenge(Origin::signed(2), move_2_hash), Error::<Test>::NotGameOwner);
+        assert_ok!(RoPaSci::cancel_challenge(Origin::signed(1), move_2_hash));
+        assert_noop!(RoPaSci::cancel_challenge(Origin::signed(1), move_2_hash), Error::<Test>::ChallengeCancelling);
+        assert_eq!(Some(&crate::Event::ChallengeCancelRequested { game_id: move_2_hash, cancel_at: 6 }),
+            ropasci_events().last());
+        assert_eq!(100 - 2 * stake, Balances::free_balance(1));
+
+        assert_noop!(
+            RoPaSci::reveal_challenge(Origin::signed(1), move_1_hash, move_1.clone()),
+            Error::<Test>::ChallengeNotAnswered,
+        );
+        assert_noop!(
+            RoPaSci::respond_challenge(Origin::signed(1), move_1_hash, Hand::Paper),
+            Error::<Test>::ChallengeOwnAnswer,
+        );
+
+        // answered much later without a round length
+        run_to_block(500);
+
+        assert!(!Challenges::<Test>::contains_key(move_2_hash));
+        assert_eq!(100 - stake, Balances::free_balance(1));
+        assert_noop!(
+            RoPaSci::respond_challenge(Origin::signed(2), move_2_hash, Hand::Paper),
+            Error::<Test>::GameMissing,
+        );
+        assert_ok!(RoPaSci::respond_challenge(Origin::signed(2), move_1_hash, Hand::Paper));
+        assert_noop!(
+            RoPaSci::respond_challenge(Origin::signed(3), move_1_hash, Hand::Rock),
+            Error::<Test>::ChallengeAnswered,
+        );
+        assert_noop!(RoPaSci::cancel_challenge(Origin::signed(1), move_1_hash), Error::<Test>::ChallengeAnswered);
+        assert_eq!(2 * stake, Escrow::<Test>::get());
+
+        assert_ok!(RoPaSci::reveal_challenge(Origin::signed(1), move_1_hash, move_1));
+
+        assert_eq!(100 + stake, Balances::free_balance(1));
+        assert_eq!(100 - stake, Balances::free_balance(2));
+        assert_eq!(0, Escrow::<Test>::get());
+        assert_eq!(Some(&crate::Event::ChallengeEnded {
+            game_id: move_1_hash,
+            challenger_hand: Some(Hand::Scissors),
+            responder_hand: Hand::Paper,
+            winner: Some(1),
+            payout: 2 * stake,
+        }), ropasci_events().last());
+    });
+}
+
+#[test]
+fn answers_challenge_until_cancelled() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let stake = 10;
+        let move_reveal = vec![Hand::Scissors as u8];
+        let move_hash = <Test as Config>::MoveHasher::hash(&move_reveal);
+
+        assert_ok!(RoPaSci::post_challenge(Origin::signed(1), stake, move_hash));
+
+        // the challenger tries to cancel ahead of a winning response
+        assert_ok!(RoPaSci::cancel_challenge(Origin::signed(1), move_hash));
+        assert_ok!(RoPaSci::respond_challenge(Origin::signed(2), move_hash, Hand::Rock));
+        assert_eq!(None, ChallengesIndex::<Test>::get(6));
+        assert_noop!(RoPaSci::cancel_challenge(Origin::signed(1), move_hash), Error::<Test>::ChallengeAnswered);
+
+        // the cancellation block passes without cancelling the answered challenge
+        run_to_block(6);
+
+        assert!(Challenges::<Test>::contains_key(move_hash));
+        assert_ok!(RoPaSci::reveal_challenge(Origin::signed(1), move_hash, move_reveal));
+        assert_eq!(100 - stake, Balances::free_balance(1));
+        assert_eq!(100 + stake, Balances::free_balance(2));
+        assert_eq!(Some(&crate::Event::ChallengeEnded {
+            game_id: move_hash,
+            challenger_hand: Some(Hand::Scissors),
+            responder_hand: Hand::Rock,
+            winner: Some(2),
+            payout: 2 * stake,
+        }), ropasci_events().last());
+    });
+}
+
+#[test]
+fn forfeits_unrevealed_challenge() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let stake = 10;
+        let move_hash = <Test as Config>::MoveHasher::hash(&[Hand::Scissors as u8]);
+
+        assert_ok!(RoPaSci::post_challenge(Origin::signed(1), stake, move_hash));
+        assert_ok!(RoPaSci::respond_challenge(Origin::signed(2), move_hash, Hand::Rock));
+
+        // the challenger does not reveal until the deadline
+        run_to_block(11);
+
+        assert_eq!(100 - stake, Balances::free_balance(1));
+        assert_eq!(100 + stake, Balances::free_balance(2));
+        assert!(!Challenges::<Test>::contains_key(move_hash));
+        assert_eq!(Some(&crate::Event::ChallengeEnded {
+            game_id: move_hash,
+            challenger_hand: None,
+            responder_hand: Hand::Rock,
+            winner: Some(2),
+            payout: 2 * stake,
+        }), ropasci_events().last());
+    });
+}
+
+#[test]
+fn opens_scheduled_games() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let round_length = 20;
+        let bet = 10;
+        let scheduled_games = || ropasci_events().into_iter()
+            .filter_map(|event| match event {
+                crate::Event::GameStarted { game_id, owner: 1, schedule: Some(0), .. } => Some(game_id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_noop!(
+            RoPaSci::schedule_game(Origin::signed(1), round_length, bet, 1, None, 0),
+            Error::<Test>::ScheduleInvalid,
+        );
+        assert_noop!(
+            RoPaSci::schedule_game(Origin::signed(1), round_length, bet, 5, None, 1),
+            Error::<Test>::ScheduleInvalid,
+        );
+        assert_noop!(
+            RoPaSci::schedule_game(Origin::signed(1), round_length, bet, 5, Some(0), 1),
+            Error::<Test>::ScheduleInvalid,
+        );
+        assert_noop!(
+            RoPaSci::schedule_game(Origin::signed(1), round_length, bet, 5, Some(30), 4),
+            Error::<Test>::ScheduleInvalid,
+        );
+        assert_ok!(RoPaSci::schedule_game(Origin::signed(1), round_length, bet, 5, Some(30), 1));
+        assert!(ropasci_events().contains(&crate::Event::GameScheduled { schedule_id: 0, owner: 1, opens_at: 5 }));
+        assert_eq!(5, Balances::reserved_balance(1));
+
+        run_to_block(5);
+
+        let game_ids = scheduled_games();
+        assert_eq!(1, game_ids.len());
+        assert!(RoPaSci::game_updates().iter().any(|update| matches!(
+            update,
+            GameUpdate::GameStarted { owner: 1, schedule: Some(0), .. }
+        )));
+        assert!(matches!(
+            Games::<Test>::get(game_ids[0]),
+            Some(Game { round_length: 20, bet: 10, stage: GameStage::Betting { participating_players: 0 }, .. })
+        ));
+        assert_ok!(RoPaSci::join(Origin::signed(2), game_ids[0], <Test as Config>::MoveHasher::hash(b"move")));
+
+        assert_eq!(Some((1, 2)), GameDeposits::<Test>::get(game_ids[0]));
+
+        // the second and last game opens a period later with the rest of the deposit
+        run_to_block(35);
+
+        let game_ids = scheduled_games();
+        assert_eq!(2, game_ids.len());
+        assert!(!Schedules::<Test>::contains_key(0));
+        assert_eq!(Some((1, 3)), GameDeposits::<Test>::get(game_ids[1]));
+        assert_eq!(5, Balances::reserved_balance(1));
+        assert!(ropasci_events().contains(&crate::Event::ScheduleCompleted { schedule_id: 0 }));
+
+        // the joined game releases its share when it ends, the game nobody joined forfeits it
+        run_to_block(55);
+
+        assert_eq!(0, Balances::reserved_balance(1));
+        assert_eq!(100 - 3, Balances::free_balance(1));
+        assert!(ropasci_events().contains(
+            &crate::Event::DepositReleased { game_id: game_ids[0], owner: 1, amount: 2 }));
+        assert!(ropasci_events().contains(
+            &crate::Event::DepositSlashed { game_id: game_ids[1], owner: 1, amount: 3 }));
+    });
+}
+
+#[test]
+fn can_cancel_schedule() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        assert_ok!(RoPaSci::schedule_game(Origin::signed(1), 20, 10, 5, Some(10), 3));
+        assert_noop!(RoPaSci::cancel_schedule(Origin::signed(2), 0), Error::<Test>::NotGameOwner);
+        assert_ok!(RoPaSci::cancel_schedule(Origin::signed(1), 0));
+        assert_noop!(RoPaSci::cancel_schedule(Origin::signed(1), 0), Error::<Test>::ScheduleMissing);
+        assert_eq!(0, Balances::reserved_balance(1));
+        assert!(!SchedulesIndex::<Test>::contains_key(5));
+        assert_eq!(0, ReservedGameIds::<Test>::iter().count());
+
+        run_to_block(5);
+
+        assert_eq!(0, Games::<Test>::iter().count());
+    });
+}
+
+#[test]
+fn reserves_scheduled_games() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let scheduled_game_id = |schedule_id: ScheduleId, opens_at: u64|
+            <Test as Config>::MoveHasher::hash_of(&(b"ropasci/schedule", schedule_id, opens_at));
+
+        assert_ok!(RoPaSci::schedule_game(Origin::signed(1), 20, 10, 5, Some(10), 1));
+        assert_noop!(
+            RoPaSci::start(Origin::signed(2), 20, 10, scheduled_game_id(0, 5)),
+            Error::<Test>::GameExists,
+        );
+        assert_ok!(RoPaSci::schedule_game(Origin::signed(2), 20, 10, 5, None, 0));
+        assert_noop!(
+            RoPaSci::schedule_game(Origin::signed(3), 20, 10, 5, None, 0),
+            Error::<Test>::ScheduleBlockFull,
+        );
+
+        // the block of the second game of the first schedule is full
+        assert_ok!(RoPaSci::schedule_game(Origin::signed(2), 20, 10, 15, None, 0));
+        assert_ok!(RoPaSci::schedule_game(Origin::signed(3), 20, 10, 15, None, 0));
+
+        run_to_block(5);
+
+        assert!(Games::<Test>::contains_key(scheduled_game_id(0, 5)));
+        assert!(ropasci_events().contains(
+            &crate::Event::ScheduledGameSkipped { schedule_id: 0, game_id: scheduled_game_id(0, 15) }));
+        assert!(ropasci_events().contains(&crate::Event::ScheduleCompleted { schedule_id: 0 }));
+        // the opened game keeps its share of the deposit, the skipped one's share is returned
+        assert_eq!(2, Balances::reserved_balance(1));
+
+        // the games of a paused block are skipped
+        assert_ok!(RoPaSci::pause(Origin::root()));
+        run_to_block(15);
+
+        assert!(!Games::<Test>::contains_key(scheduled_game_id(2, 15)));
+        assert!(ropasci_events().contains(
+            &crate::Event::ScheduledGameSkipped { schedule_id: 2, game_id: scheduled_game_id(2, 15) }));
+        assert!(ropasci_events().contains(
+            &crate::Event::ScheduledGameSkipped { schedule_id: 3, game_id: scheduled_game_id(3, 15) }));
+        assert_eq!(0, ReservedGameIds::<Test>::iter().count());
+    });
+}
+
+#[test]
+fn can_rematch() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let bet = 10;
+        let game_id = play_game(bet, false, &[(1, Hand::Rock), (2, Hand::Rock)]);
+        let rematch_id = <Test as Config>::MoveHasher::hash_of(&(b"ropasci/rematch", game_id));
+        let (move_1, move_2) = (vec![Hand::Paper as u8, 1], vec![Hand::Rock as u8, 2]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+
+        assert_noop!(RoPaSci::rematch(Origin::signed(3), game_id, move_1_hash), Error::<Test>::NotGamePlayer);
+        assert_noop!(RoPaSci::rematch(Origin::signed(1), move_1_hash, move_1_hash), Error::<Test>::GameMissing);
+        assert_ok!(RoPaSci::rematch(Origin::signed(1), game_id, move_1_hash));
+        assert_noop!(RoPaSci::rematch(Origin::signed(1), game_id, move_1_hash), Error::<Test>::PlayerMoveMade);
+        assert!(!Games::<Test>::contains_key(rematch_id));
+        assert_noop!(RoPaSci::start(Origin::signed(3), 20, bet, rematch_id), Error::<Test>::GameExists);
+        assert_eq!(bet, Escrow::<Test>::get());
+
+        assert_ok!(RoPaSci::rematch(Origin::signed(2), game_id, move_2_hash));
+
+        assert!(matches!(
+            RoPaSci::game(rematch_id),
+            Some(Game { stage: GameStage::Revealing { anticipated_players: 2 }, .. })
+        ));
+        assert_noop!(RoPaSci::join(Origin::signed(3), rematch_id, move_1_hash), Error::<Test>::GameClosed);
+        assert_noop!(RoPaSci::rematch(Origin::signed(1), game_id, move_1_hash), Error::<Test>::RematchUnavailable);
+        assert_eq!(Some(&crate::Event::RematchStarted { game_id: rematch_id, previous: game_id }), ropasci_events().last());
+
+        assert_ok!(RoPaSci::reveal(Origin::signed(1), rematch_id, move_1));
+        assert_ok!(RoPaSci::reveal(Origin::signed(2), rematch_id, move_2));
+
+        assert_eq!(100 + bet, Balances::free_balance(1));
+        assert_eq!(100 - bet, Balances::free_balance(2));
+        assert!(!RematchGames::<Test>::contains_key(rematch_id));
+        assert!(matches!(
+            RoPaSci::game_result(rematch_id),
+            Some(GameResult { winners, rematchable: true, .. }) if winners == vec![1]
+        ));
+    });
+}
+
+#[test]
+fn expires_rematch() {
+    build_and_execute(|| {
+        run_to_block(1);
+
+        let bet = 10;
+        let game_id = play_game(bet, false, &[(1, Hand::Rock), (2, Hand::Paper)]);
+        let start = System::block_number();
+
+        assert_ok!(RoPaSci::rematch(Origin::signed(1), game_id, <Test as Config>::MoveHasher::hash(b"move")));
+        assert_eq!(100 - 2 * bet, Balances::free_balance(1));
+
+        // the other player does not commit within the window
+        run_to_block(start + 10);
+
+        assert_eq!(100 - bet, Balances::free_balance(1));
+        assert_eq!(0, Escrow::<Test>::get());
+        assert!(!Rematches::<Test>::contains_key(game_id));
+        assert_eq!(0, ReservedGameIds::<Test>::iter().count());
+        assert_eq!(0, ActiveGames::<Test>::get(1));
+        assert_eq!(Some(&crate::Event::RematchExpired {
+            game_id,
+            players: vec![1],
+            amount: bet,
+        }), ropasci_events().last());
+    });
+}
+
+#[test]
+fn rejects_rematch_before_a_round_passed() {
+    build_and_execute(|| {
+        run_to_block(5);
+
+        let game_id = <Test as Config>::MoveHasher::hash(b"game");
+        GameResults::<Test>::insert(game_id, GameResult {
+            ended_at: 5,
+            round_length: 20,
+            bet: 10,
+            winners: Default::default(),
+            players: vec![(1, Some(Hand::Rock), 0), (2, Some(Hand::Rock), 0)].try_into().unwrap(),
+            rematchable: true,
+        });
+
+        // the rematch would have to start revealing before the first block
+        assert_noop!(
+            RoPaSci::rematch(Origin::signed(1), game_id, <Test as Config>::MoveHasher::hash(b"move")),
+            Error::<Test>::RematchUnavailable,
+        );
+
+        run_to_block(20);
+
+        assert_ok!(RoPaSci::rematch(Origin::signed(1), game_id, <Test as Config>::MoveHasher::hash(b"move")));
+    });
+}
+
+#[test]
+fn can_reveal_unsigned() {
+    build_and_execute(|| {
+        let round_length = 20;
+        let bet = 10;
+        let (move_1, move_2) = (vec![Hand::Rock as u8], vec![Hand::Paper as u8]);
+        let move_1_hash = <Test as Config>::MoveHasher::hash(&move_1);
+        let move_2_hash = <Test as Config>::MoveHasher::hash(&move_2);
+
+        assert_ok!(RoPaSci::start(Origin::signed(1), round_length, bet, move_1_hash));
+        assert_ok!(RoPaSci::join(Origin::signed(2), move_1_hash, move_2_hash));
+
+        let payload = RevealPayload { player: 2, game_id: move_1_hash, move_reveal: move_2.clone(), nonce: 0 };
+        let sign = |player: u64, payload: &RevealPayload<u64, H256>|
+            TestSignature(player, RoPaSci::reveal_signing_payload(payload));
+        let signature = sign(2, &payload);
+        let validate = |payload: &RevealPayload<u64, H256>, signature: &TestSignature| {
+            let call = crate::Call::reveal_unsigned { payload: payload.clone(), signature: signature.clone() };
+            <RoPaSci as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call)
+        };
+
+        // still betting
+        assert_eq!(InvalidTransaction::Future.into(), validate(&payload, &signature));
+
+        // end betting
+        run_to_block(20);
+
+        let valid = validate(&payload, &signature).unwrap();
+        assert_eq!(1000 - 20, valid.priority);
+        assert_eq!(20, valid.longevity);
+        assert!(valid.requires.is_empty());
+        assert_eq!(InvalidTransaction::BadProof.into(), validate(&payload, &sign(1, &payload)));
+        // the bare payload without the domain and the genesis hash
+        assert_eq!(InvalidTransaction::BadProof.into(), validate(&payload, &TestSignature(2, payload.encode())));
+        let mismatch = RevealPayload { move_reveal: move_1.clone(), ..payload.clone() };
+        assert_eq!(InvalidTransaction::BadProof.into(), validate(&mismatch, &sign(2, &mismatch)));
+
+        // a future nonce waits in the pool for the reveal of the previous one
+        let future = RevealPayload { nonce: 1, ..payload.clone() };
+        let future_valid = validate(&future, &sign(2, &future)).unwrap();
+        assert_eq!(vec![valid.provides[0].clone()], future_valid.requires);
+
+        assert_noop!(RoPaSci::reveal_unsigned(Origin::signed(2), payload.clone(), signature.clone()), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(RoPaSci::reveal_unsigned(Origin::none(), payload.clone(), signature.clone()));
+
+        assert_eq!(1, RevealNonces::<Test>::get(2));
+        assert_eq!(Some(MoveStatus::Revealed(Hand::Paper)), RoPaSci::player_move_status(move_1_hash, 2));
+        assert_eq!(InvalidTransaction::Stale.into(), validate(&payload, &signature));
+        assert_noop!(
+            RoPaSci::reveal_unsigned(Origin::none(), payload, signature),
+            Error::<Test>::RevealNonceInvalid,
+        );
+    });
+}
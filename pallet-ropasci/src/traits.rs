@@ -0,0 +1,26 @@
+use frame_support::weights::Weight;
+
+use crate::Hand;
+
+/// Handler for the end of a game.
+pub trait OnGameEnded<AccountId, GameId, Balance> {
+    /// A game has ended. Every player of the game is provided with the revealed hand, if any, and
+    /// the payout.
+    fn on_game_ended(game_id: &GameId, players: &[(AccountId, Option<Hand>, Balance)]);
+
+    /// The weight of `on_game_ended` for a game of the given number of players.
+    fn weight(players: u32) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId, GameId, Balance> OnGameEnded<AccountId, GameId, Balance> for Tuple {
+    fn on_game_ended(game_id: &GameId, players: &[(AccountId, Option<Hand>, Balance)]) {
+        for_tuples!( #( Tuple::on_game_ended(game_id, players); )* );
+    }
+
+    fn weight(players: u32) -> Weight {
+        let mut weight: Weight = 0;
+        for_tuples!( #( weight = weight.saturating_add(Tuple::weight(players)); )* );
+        weight
+    }
+}
@@ -0,0 +1,329 @@
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    /// Checks the invariants of the pallet storage. Intended to be run by `try-runtime` and after
+    /// every test of the mock runtime.
+    pub fn do_try_state() -> Result<(), &'static str> {
+        Self::check_games_indexed()?;
+        Self::check_moves()?;
+        Self::check_escrow()?;
+        Self::check_deposits()?;
+        Self::check_house_games()?;
+        Self::check_challenges()?;
+        Self::check_schedules()?;
+        Self::check_rematches()?;
+        Self::check_active_games()
+    }
+
+    /// Every game must be present exactly once in the index matching its stage, at a future block.
+    /// Index entries of the games which already ended are allowed as they are skipped anyway.
+    fn check_games_indexed() -> Result<(), &'static str> {
+        let now = Self::now();
+        let mut betting = BTreeMap::<GameId<T>, u32>::new();
+        let mut revealing = BTreeMap::<GameId<T>, u32>::new();
+
+        for (block, game_ids) in BettingGamesIndex::<T>::iter() {
+            for game_id in game_ids.into_iter().filter(|game_id| Games::<T>::contains_key(game_id)) {
+                ensure!(block > now, "Betting game is indexed at a past block");
+                *betting.entry(game_id).or_default() += 1;
+            }
+        }
+
+        for (block, game_ids) in RevealingGamesIndex::<T>::iter() {
+            for game_id in game_ids.into_iter().filter(|game_id| Games::<T>::contains_key(game_id)) {
+                ensure!(block > now, "Revealing game is indexed at a past block");
+                *revealing.entry(game_id).or_default() += 1;
+            }
+        }
+
+        for (game_id, game) in Games::<T>::iter() {
+            let (betting_count, revealing_count) = (
+                betting.get(&game_id).copied().unwrap_or_default(),
+                revealing.get(&game_id).copied().unwrap_or_default(),
+            );
+
+            match game.stage {
+                GameStage::Betting { .. } => ensure!(
+                    betting_count == 1 && revealing_count == 0,
+                    "Betting game is not indexed exactly once"
+                ),
+                GameStage::Revealing { .. } => ensure!(
+                    betting_count == 0 && revealing_count == 1,
+                    "Revealing game is not indexed exactly once"
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The moves of every game must match the players count of its stage and there must be no
+    /// moves of the games which do not exist.
+    fn check_moves() -> Result<(), &'static str> {
+        for (game_id, game) in Games::<T>::iter() {
+            let moves = Moves::<T>::iter_prefix_values(game_id).collect::<Vec<_>>();
+
+            match game.stage {
+                GameStage::Betting { participating_players } => ensure!(
+                    moves.len() as u64 == participating_players,
+                    "Moves count does not match participating players"
+                ),
+                GameStage::Revealing { anticipated_players } => ensure!(
+                    moves.iter().filter(|m| m.hand.is_none()).count() as u64 == anticipated_players,
+                    "Unrevealed moves count does not match anticipated players"
+                ),
+            }
+        }
+
+        ensure!(
+            Moves::<T>::iter_keys().all(|(game_id, _)| Games::<T>::contains_key(game_id)),
+            "Moves exist for a missing game"
+        );
+
+        Ok(())
+    }
+
+    /// The escrowed funds must be equal to the sum of the bets of all the active native currency
+    /// games, the stakes of all the side-bets and challenges and the bets committed to rematches.
+    /// The pallet account must hold the escrowed funds, the unclaimed native currency payouts, the
+    /// jackpot and the season prize pool.
+    fn check_escrow() -> Result<(), &'static str> {
+        let outstanding_bets = Games::<T>::iter()
+            .filter(|(game_id, _)| !GameAssets::<T>::contains_key(game_id))
+            .map(|(game_id, game)| {
+                // the owner of a prize game escrows the item instead of the bet
+                let bettors = Moves::<T>::iter_prefix(game_id).count()
+                    .saturating_sub(GamePrizes::<T>::contains_key(game_id) as usize);
+                game.bet.saturating_mul(BalanceOf::<T>::saturated_from(bettors))
+            })
+            .chain(SideBets::<T>::iter_values().map(|(_, stake)| stake))
+            .chain(Challenges::<T>::iter_values().map(|challenge| {
+                let players = 1 + challenge.response.is_some() as u32;
+                challenge.stake.saturating_mul(players.into())
+            }))
+            .chain(Rematches::<T>::iter_values().map(|rematch| {
+                rematch.bet.saturating_mul(BalanceOf::<T>::saturated_from(rematch.moves.len()))
+            }))
+            .fold(BalanceOf::<T>::default(), |total, bets| total.saturating_add(bets));
+
+        ensure!(outstanding_bets == Escrow::<T>::get(), "Escrow does not match outstanding bets");
+
+        let unclaimed = UnclaimedPayouts::<T>::iter()
+            .filter(|(_, asset, _)| asset.is_none())
+            .fold(BalanceOf::<T>::default(), |total, (_, _, amount)| total.saturating_add(amount));
+        let held = outstanding_bets
+            .saturating_add(unclaimed)
+            .saturating_add(Jackpot::<T>::get())
+            .saturating_add(SeasonPrizePool::<T>::get());
+        ensure!(
+            T::Currency::free_balance(&Self::account_id()) >= held,
+            "Pallet account does not hold the escrowed funds"
+        );
+        Ok(())
+    }
+
+    /// There must be no deposits, assets, teams, tie-breaks, jackpot rollovers or side-bets of the
+    /// games which do not exist and the side-bet counts must match the side-bets. The prizes of such
+    /// games are the unclaimed ones.
+    fn check_deposits() -> Result<(), &'static str> {
+        ensure!(
+            GameDeposits::<T>::iter_keys().all(|game_id| Games::<T>::contains_key(game_id)),
+            "Deposit exists for a missing game"
+        );
+        ensure!(
+            GameAssets::<T>::iter_keys().all(|game_id| Games::<T>::contains_key(game_id)),
+            "Asset exists for a missing game"
+        );
+        ensure!(
+            GameTieBreaks::<T>::iter_keys().all(|game_id| Games::<T>::contains_key(game_id)),
+            "Tie-break exists for a missing game"
+        );
+        ensure!(
+            JackpotRollovers::<T>::iter_keys().all(|game_id| Games::<T>::contains_key(game_id)),
+            "Jackpot rollover exists for a missing game"
+        );
+        ensure!(
+            TeamGames::<T>::iter_keys().all(|game_id| Games::<T>::contains_key(game_id)),
+            "Team game is missing"
+        );
+        ensure!(
+            TeamMembers::<T>::iter_keys().all(|(game_id, player)| Moves::<T>::contains_key(game_id, player)),
+            "Team member has no move"
+        );
+        ensure!(
+            SideBets::<T>::iter_keys().all(|(game_id, _)| Games::<T>::contains_key(game_id)),
+            "Side-bet exists for a missing game"
+        );
+        ensure!(
+            SideBets::<T>::iter_keys().all(|(game_id, _)| SideBetCounts::<T>::contains_key(game_id)) &&
+                SideBetCounts::<T>::iter()
+                    .all(|(game_id, count)| SideBets::<T>::iter_prefix(game_id).count() as u32 == count),
+            "Side-bet count does not match the side-bets"
+        );
+
+        Ok(())
+    }
+
+    /// Every game against the house must be indexed exactly once at its deadline and the house
+    /// liability must be equal to the sum of the winnings of all of them and the debts of the house.
+    fn check_house_games() -> Result<(), &'static str> {
+        let now = Self::now();
+        let mut indexed = BTreeMap::<GameId<T>, u32>::new();
+        for (block, game_ids) in HouseGamesIndex::<T>::iter() {
+            for game_id in game_ids {
+                ensure!(block > now, "House game is indexed at a past block");
+                *indexed.entry(game_id).or_default() += 1;
+            }
+        }
+
+        let mut liability = BalanceOf::<T>::default();
+        for (game_id, game) in HouseGames::<T>::iter() {
+            ensure!(
+                HouseGamesIndex::<T>::get(game.deadline).map_or(false, |ids| ids.contains(&game_id)),
+                "House game is not indexed at its deadline"
+            );
+            ensure!(indexed.remove(&game_id) == Some(1), "House game is not indexed exactly once");
+            liability = liability.saturating_add(Self::house_win_payout(game.bet));
+        }
+        ensure!(indexed.is_empty(), "House games index contains a missing game");
+        let liability = HouseDebts::<T>::iter_values()
+            .fold(liability, |total, debt| total.saturating_add(debt));
+        ensure!(liability == HouseLiability::<T>::get(), "House liability does not match the games");
+
+        Ok(())
+    }
+
+    /// Every answered challenge must be indexed exactly once at its deadline, every challenge whose
+    /// cancellation is requested at its cancellation block and there must be no index entries of
+    /// the other challenges.
+    fn check_challenges() -> Result<(), &'static str> {
+        let now = Self::now();
+        let mut indexed = BTreeMap::<GameId<T>, u32>::new();
+        for (block, game_ids) in ChallengesIndex::<T>::iter() {
+            for game_id in game_ids {
+                ensure!(block > now, "Challenge is indexed at a past block");
+                *indexed.entry(game_id).or_default() += 1;
+            }
+        }
+
+        for (game_id, challenge) in Challenges::<T>::iter() {
+            ensure!(
+                challenge.response.is_some() == challenge.deadline.is_some(),
+                "Challenge deadline does not match its response"
+            );
+            ensure!(
+                !(challenge.response.is_some() && challenge.cancel_at.is_some()),
+                "Answered challenge is being cancelled"
+            );
+            if let Some(at) = challenge.deadline.or(challenge.cancel_at) {
+                ensure!(
+                    ChallengesIndex::<T>::get(at).map_or(false, |ids| ids.contains(&game_id)),
+                    "Challenge is not indexed at its deadline"
+                );
+                ensure!(indexed.remove(&game_id) == Some(1), "Challenge is not indexed exactly once");
+            }
+        }
+        ensure!(indexed.is_empty(), "Challenges index contains an open or missing challenge");
+
+        Ok(())
+    }
+
+    /// Every schedule must be indexed exactly once at the future block of its next game, whose id
+    /// must be reserved, and no block may open more than `MaxSchedulesPerBlock` schedules. Every
+    /// reserved game id must be the next game of a schedule or the game of a rematch.
+    fn check_schedules() -> Result<(), &'static str> {
+        let now = Self::now();
+        let mut indexed = BTreeMap::<ScheduleId, u32>::new();
+        for (block, schedule_ids) in SchedulesIndex::<T>::iter() {
+            ensure!(
+                schedule_ids.len() <= T::MaxSchedulesPerBlock::get() as usize,
+                "Block opens too many schedules"
+            );
+            for schedule_id in schedule_ids {
+                ensure!(block > now, "Schedule is indexed at a past block");
+                *indexed.entry(schedule_id).or_default() += 1;
+            }
+        }
+
+        let mut reserved = ReservedGameIds::<T>::iter_keys().collect::<BTreeSet<_>>();
+        for (schedule_id, schedule) in Schedules::<T>::iter() {
+            ensure!(indexed.remove(&schedule_id) == Some(1), "Schedule is not indexed exactly once");
+            ensure!(
+                SchedulesIndex::<T>::get(schedule.opens_at).map_or(false, |ids| ids.contains(&schedule_id)),
+                "Schedule is not indexed at its next game"
+            );
+            ensure!(
+                reserved.remove(&Self::scheduled_game_id(schedule_id, schedule.opens_at)),
+                "Scheduled game id is not reserved"
+            );
+        }
+        ensure!(indexed.is_empty(), "Schedules index contains a missing schedule");
+        for game_id in Rematches::<T>::iter_keys() {
+            reserved.remove(&Self::rematch_id(&game_id));
+        }
+        ensure!(reserved.is_empty(), "Game id is reserved for no schedule or rematch");
+        Ok(())
+    }
+
+    /// Every rematch must have a move committed by one of its players, its game id reserved and be
+    /// indexed at its expiry block, which must be in the future. Index entries of the started
+    /// rematches are allowed as they are skipped anyway. Every rematch game must exist.
+    fn check_rematches() -> Result<(), &'static str> {
+        let now = Self::now();
+        for (game_id, rematch) in Rematches::<T>::iter() {
+            ensure!(
+                !rematch.moves.is_empty() &&
+                    rematch.moves.iter().all(|(player, _)| rematch.players.contains(player)),
+                "Rematch moves do not match its players"
+            );
+            ensure!(rematch.expires_at > now, "Rematch expires at a past block");
+            ensure!(
+                RematchesIndex::<T>::get(rematch.expires_at).map_or(false, |ids| ids.contains(&game_id)),
+                "Rematch is not indexed at its expiry"
+            );
+            ensure!(
+                ReservedGameIds::<T>::contains_key(Self::rematch_id(&game_id)),
+                "Rematch game id is not reserved"
+            );
+        }
+        ensure!(
+            RematchGames::<T>::iter_keys().all(|game_id| Games::<T>::contains_key(game_id)),
+            "Rematch game is missing"
+        );
+
+        Ok(())
+    }
+
+    /// The active games counter of every account must match the number of games it made a move in,
+    /// the games it plays against the house, the challenges it posted or answered and the rematches
+    /// it committed to.
+    fn check_active_games() -> Result<(), &'static str> {
+        let mut active_games = BTreeMap::<T::AccountId, u32>::new();
+        for (_, player) in Moves::<T>::iter_keys() {
+            *active_games.entry(player).or_default() += 1;
+        }
+        for game in HouseGames::<T>::iter_values() {
+            *active_games.entry(game.player).or_default() += 1;
+        }
+        for challenge in Challenges::<T>::iter_values() {
+            *active_games.entry(challenge.challenger).or_default() += 1;
+            if let Some((responder, _)) = challenge.response {
+                *active_games.entry(responder).or_default() += 1;
+            }
+        }
+        for rematch in Rematches::<T>::iter_values() {
+            for (player, _) in rematch.moves {
+                *active_games.entry(player).or_default() += 1;
+            }
+        }
+
+        ensure!(
+            ActiveGames::<T>::iter().collect::<BTreeMap<_, _>>() == active_games,
+            "Active games counters do not match the moves"
+        );
+
+        Ok(())
+    }
+}
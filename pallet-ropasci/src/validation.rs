@@ -1,27 +1,103 @@
-use sp_runtime::traits::{Get, Hash};
+use sp_runtime::traits::Hash;
 
 use crate::*;
 
 impl<T: Config> Pallet<T> {
-    pub(crate) fn valid_round_length(round_length: T::BlockNumber) -> bool {
-        round_length >= T::MinRoundLength::get().into() &&
-            round_length <= T::MaxRoundLength::get().into()
+    pub(crate) fn valid_round_length(config: &GameConfigOf<T>, round_length: T::BlockNumber) -> bool {
+        round_length >= config.min_round_length.into() &&
+            round_length <= config.max_round_length.into()
+    }
+
+    pub(crate) fn valid_bet(config: &GameConfigOf<T>, bet: BalanceOf<T>) -> bool {
+        bet >= config.min_bet && bet <= config.max_bet
+    }
+
+    /// The bet limits only apply to the native currency, any non-zero bet is valid for an asset.
+    pub(crate) fn valid_asset_bet(
+        config: &GameConfigOf<T>,
+        asset: Option<AssetIdOf<T>>,
+        bet: BalanceOf<T>,
+    ) -> bool {
+        match asset {
+            None => Self::valid_bet(config, bet),
+            Some(_) => !bet.is_zero(),
+        }
     }
 
     pub(crate) fn can_create_game(game_id: &GameId<T>) -> bool {
-        !Games::<T>::contains_key(game_id)
+        !Games::<T>::contains_key(game_id) &&
+            !HouseGames::<T>::contains_key(game_id) &&
+            !Challenges::<T>::contains_key(game_id) &&
+            // an unclaimed prize item of an ended game is kept under its id
+            !GamePrizes::<T>::contains_key(game_id) &&
+            !ReservedGameIds::<T>::contains_key(game_id)
     }
 
     pub(crate) fn can_join_game(game: &GameOf<T>) -> bool {
         matches!(game.stage, GameStage::Betting{..})
     }
 
+    pub(crate) fn has_free_seat(config: &GameConfigOf<T>, game: &GameOf<T>) -> bool {
+        matches!(game.stage, GameStage::Betting{participating_players} if participating_players < config.max_players.into())
+    }
+
+    pub(crate) fn valid_schedule(
+        opens_at: T::BlockNumber,
+        period: Option<T::BlockNumber>,
+        repeats: u32,
+    ) -> bool {
+        opens_at > Self::now() &&
+            repeats <= T::MaxScheduleRepeats::get() &&
+            period.map_or(repeats == 0, |period| !period.is_zero())
+    }
+
+    pub(crate) fn has_schedule_room(opens_at: T::BlockNumber) -> bool {
+        SchedulesIndex::<T>::decode_len(opens_at).unwrap_or(0) < T::MaxSchedulesPerBlock::get() as usize
+    }
+
+    pub(crate) fn valid_team_size(config: &GameConfigOf<T>, team_size: u32) -> bool {
+        team_size >= 2 && team_size.saturating_mul(2) <= config.max_players
+    }
+
+    pub(crate) fn has_team_seat(game_id: &GameId<T>, team: Team, team_size: u32) -> bool {
+        TeamMembers::<T>::iter_prefix_values(game_id)
+            .filter(|member_team| *member_team == team)
+            .count() < team_size as usize
+    }
+
+    pub(crate) fn has_full_teams(game_id: &GameId<T>, team_size: u32) -> bool {
+        let (first, second) = TeamMembers::<T>::iter_prefix_values(game_id)
+            .fold((0u32, 0u32), |(first, second), team| match team {
+                Team::First => (first.saturating_add(1), second),
+                Team::Second => (first, second.saturating_add(1)),
+            });
+        first == team_size && second == team_size
+    }
+
     pub(crate) fn can_make_move(game_id: &GameId<T>, player: &T::AccountId) -> bool {
         !Moves::<T>::contains_key(game_id, player)
     }
 
     pub(crate) fn can_reveal_move(game: &GameOf<T>) -> bool {
-        matches!(game.stage, GameStage::Revealing{..})
+        // a game with a random tie-break stays in "revealing" stage past the deadline until drawn
+        matches!(game.stage, GameStage::Revealing{..}) && Self::now() < Self::reveal_deadline(game)
+    }
+
+    pub(crate) fn is_self_excluded(player: &T::AccountId) -> bool {
+        SelfExclusions::<T>::get(player).map_or(false, |until| Self::now() < until)
+    }
+
+    pub(crate) fn has_free_game_slot(player: &T::AccountId) -> bool {
+        ActiveGames::<T>::get(player) < T::MaxActiveGamesPerAccount::get()
+    }
+
+    pub(crate) fn within_spending_cap(player: &T::AccountId, bet: BalanceOf<T>) -> bool {
+        Self::spending_cap(player).map_or(true, |cap| {
+            let spent = Spendings::<T>::get(player)
+                .filter(|(period, _)| *period == Self::spending_period())
+                .map_or_else(Zero::zero, |(_, spent)| spent);
+            spent.saturating_add(bet) <= cap
+        })
     }
 
     pub(crate) fn reveal_match(move_reveal: &Vec<u8>, move_hash: &T::MoveHash) -> bool {
@@ -15,26 +15,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for pallet_ropasci
+//! Weights for pallet_ropasci
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2022-09-20, STEPS: `20`, REPEAT: 50, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! HOSTNAME: `kalan-ThinkPad-T470s`, CPU: `Intel(R) Core(TM) i7-7500U CPU @ 2.70GHz`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/release/node-template
-// benchmark
-// pallet
-// --chain=dev
-// --execution=wasm
-// --wasm-execution=compiled
-// --pallet=pallet_ropasci
-// --extrinsic=*
-// --steps=20
-// --repeat=50
-// --template=./.maintain/frame-weight-template.hbs
-// --output=./pallet-ropasci/src/weights.rs
+//! The values are estimates: the file is not generated by a benchmark run of the current pallet,
+//! the weights of the later calls and components are written by hand after the benchmarked ones.
+//! Regenerate it on the reference hardware before relying on the values:
+//!
+//! ```text
+//! ./target/release/node-template benchmark pallet --chain=dev --execution=wasm \
+//!     --wasm-execution=compiled --pallet=pallet_ropasci --extrinsic=* --steps=20 --repeat=50 \
+//!     --template=./.maintain/frame-weight-template.hbs --output=./pallet-ropasci/src/weights.rs
+//! ```
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -47,126 +38,1320 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn start_game() -> Weight;
 	fn join_game() -> Weight;
-	fn reveal_move(r: u32, ) -> Weight;
+	fn reveal_move(r: u32, p: u32, ) -> Weight;
 	fn on_initialize_betting(g: u32, ) -> Weight;
 	fn on_initialize_revealing(g: u32, ) -> Weight;
+	fn set_config() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn force_end(p: u32, ) -> Weight;
+	fn force_refund(p: u32, ) -> Weight;
+	fn self_exclude() -> Weight;
+	fn set_spending_cap() -> Weight;
+	fn start_game_with_asset() -> Weight;
+	fn start_game_with_prize() -> Weight;
+	fn play_house() -> Weight;
+	fn reveal_house() -> Weight;
+	fn on_initialize_house(g: u32, ) -> Weight;
+	fn set_tie_break() -> Weight;
+	fn set_jackpot_rollover() -> Weight;
+	fn side_bet() -> Weight;
+	fn start_team_game() -> Weight;
+	fn join_team() -> Weight;
+	fn post_challenge() -> Weight;
+	fn cancel_challenge() -> Weight;
+	fn respond_challenge() -> Weight;
+	fn reveal_challenge() -> Weight;
+	fn on_initialize_challenges(g: u32, ) -> Weight;
+	fn schedule_game() -> Weight;
+	fn cancel_schedule() -> Weight;
+	fn on_initialize_schedules(g: u32, ) -> Weight;
+	fn rematch() -> Weight;
+	fn on_initialize_rematches(g: u32, ) -> Weight;
+	fn reveal_unsigned(r: u32, p: u32, ) -> Weight;
+	fn join_game_with_asset() -> Weight;
+	fn claim_payout() -> Weight;
+	fn withdraw_asset_rake() -> Weight;
+	fn claim_prize() -> Weight;
+	fn fund_house() -> Weight;
+	fn withdraw_house() -> Weight;
+	fn claim_house_payout() -> Weight;
+	fn settle_side_bets(s: u32, ) -> Weight;
 }
 
-/// Weights for pallet_ropasci using the Substrate node and recommended hardware.
+/// Weights for pallet_ropasci estimated for the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: RoPaSci Games (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
 	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
 	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: RoPaSci Spendings (r:1 w:0)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
 	fn start_game() -> Weight {
-		(54_628_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+		(68_435_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
 	// Storage: RoPaSci Games (r:1 w:1)
 	// Storage: RoPaSci Moves (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: RoPaSci Spendings (r:1 w:0)
+	// Storage: RoPaSci GameAssets (r:1 w:0)
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
 	fn join_game() -> Weight {
-		(58_212_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+		(66_903_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
 	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci RevealingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: RoPaSci CurrentSeason (r:1 w:0)
+	// Storage: RoPaSci Leaderboard (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:0 w:1)
+	// Storage: RoPaSci GameResultsIndex (r:1 w:1)
+	// Storage: RoPaSci Ratings (r:2 w:2)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:1 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci TeamMembers (r:1 w:1)
+	// Storage: RoPaSci RematchGames (r:1 w:1)
 	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci PlayerStats (r:1 w:1)
+	// Storage: RoPaSci SeasonProfits (r:1 w:1)
 	/// The range of component `r` is `[1, 10000000]`.
-	fn reveal_move(r: u32, ) -> Weight {
-		(0 as Weight)
+	/// The range of component `p` is `[2, 1000]`.
+	fn reveal_move(r: u32, p: u32, ) -> Weight {
+		(84_000_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			// Standard Error: 31_000
+			.saturating_add((18_783_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(19 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(p as Weight)))
 	}
 	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
 	// Storage: RoPaSci Games (r:1 w:1)
 	// Storage: RoPaSci RevealingGamesIndex (r:2 w:2)
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci System Account (r:1 w:1)
 	/// The range of component `g` is `[1, 10000]`.
 	fn on_initialize_betting(g: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 57_000
 			.saturating_add((12_924_000 as Weight).saturating_mul(g as Weight))
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(g as Weight)))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(g as Weight)))
 	}
 	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
 	// Storage: RoPaSci RevealingGamesIndex (r:1 w:1)
 	// Storage: RoPaSci Games (r:1 w:1)
 	// Storage: RoPaSci Moves (r:2 w:1)
-	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci ActiveGames (r:2 w:2)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:1 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci TeamMembers (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci CurrentSeason (r:1 w:0)
+	// Storage: RoPaSci PlayerStats (r:2 w:2)
+	// Storage: RoPaSci SeasonProfits (r:2 w:2)
+	// Storage: RoPaSci Leaderboard (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:0 w:1)
+	// Storage: RoPaSci GameResultsIndex (r:1 w:1)
+	// Storage: RoPaSci Ratings (r:2 w:2)
+	// Storage: RoPaSci RematchGames (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
 	/// The range of component `g` is `[1, 10000]`.
 	fn on_initialize_revealing(g: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 449_000
 			.saturating_add((103_314_000 as Weight).saturating_mul(g as Weight))
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().reads((27 as Weight).saturating_mul(g as Weight)))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((25 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: RoPaSci PalletConfig (r:0 w:1)
+	fn set_config() -> Weight {
+		(14_532_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:0 w:1)
+	fn pause() -> Weight {
+		(12_104_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:0 w:1)
+	fn unpause() -> Weight {
+		(12_257_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: RoPaSci CurrentSeason (r:1 w:0)
+	// Storage: RoPaSci Leaderboard (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:0 w:1)
+	// Storage: RoPaSci GameResultsIndex (r:1 w:1)
+	// Storage: RoPaSci Ratings (r:2 w:2)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:1 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci TeamMembers (r:1 w:1)
+	// Storage: RoPaSci RematchGames (r:1 w:1)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci PlayerStats (r:1 w:1)
+	// Storage: RoPaSci SeasonProfits (r:1 w:1)
+	/// The range of component `p` is `[1, 1000]`.
+	fn force_end(p: u32, ) -> Weight {
+		(98_411_000 as Weight)
+			// Standard Error: 31_000
+			.saturating_add((18_783_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(19 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:0 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:0 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci RematchGames (r:0 w:1)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	/// The range of component `p` is `[1, 1000]`.
+	fn force_refund(p: u32, ) -> Weight {
+		(55_000_000 as Weight)
+			// Standard Error: 19_000
+			.saturating_add((10_825_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: RoPaSci SelfExclusions (r:1 w:1)
+	fn self_exclude() -> Weight {
+		(19_806_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci SpendingCaps (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:1)
+	fn set_spending_cap() -> Weight {
+		(16_391_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	// Storage: RoPaSci GameAssets (r:0 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn start_game_with_asset() -> Weight {
+		(93_560_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn start_game_with_prize() -> Weight {
+		(88_417_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci HouseGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci HouseLiability (r:1 w:1)
+	// Storage: RoPaSci HouseGamesIndex (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn play_house() -> Weight {
+		(71_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: RoPaSci HouseGames (r:1 w:1)
+	// Storage: RoPaSci HouseGamesIndex (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci HouseLiability (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	fn reveal_house() -> Weight {
+		(64_871_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: RoPaSci HouseGamesIndex (r:2 w:2)
+	// Storage: RoPaSci HouseGames (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// The range of component `g` is `[1, 10000]`.
+	fn on_initialize_house(g: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 63_000
+			.saturating_add((24_518_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(g as Weight)))
 			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(g as Weight)))
 	}
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci GameDeposits (r:1 w:0)
+	// Storage: RoPaSci GameTieBreaks (r:0 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	fn set_tie_break() -> Weight {
+		(24_176_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci GameDeposits (r:1 w:0)
+	// Storage: RoPaSci GameAssets (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci JackpotRollovers (r:0 w:1)
+	fn set_jackpot_rollover() -> Weight {
+		(26_032_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci Moves (r:2 w:0)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci SideBetCounts (r:1 w:1)
+	fn side_bet() -> Weight {
+		(52_417_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci TeamGames (r:0 w:1)
+	// Storage: RoPaSci TeamMembers (r:0 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn start_team_game() -> Weight {
+		(81_339_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	// Storage: RoPaSci TeamMembers (r:4 w:1)
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:0)
+	// Storage: RoPaSci GameAssets (r:1 w:0)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	fn join_team() -> Weight {
+		(67_902_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(18 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn post_challenge() -> Weight {
+		(54_713_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci ChallengesIndex (r:1 w:1)
+	fn cancel_challenge() -> Weight {
+		(24_107_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci ChallengesIndex (r:2 w:2)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	fn respond_challenge() -> Weight {
+		(57_940_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci ChallengesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:2 w:2)
+	fn reveal_challenge() -> Weight {
+		(62_105_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RoPaSci ChallengesIndex (r:1 w:1)
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:2 w:2)
+	/// The range of component `g` is `[1, 10000]`.
+	fn on_initialize_challenges(g: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 71_000
+			.saturating_add((38_771_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci NextScheduleId (r:1 w:1)
+	// Storage: RoPaSci Schedules (r:0 w:1)
+	// Storage: RoPaSci SchedulesIndex (r:1 w:1)
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn schedule_game() -> Weight {
+		(48_312_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RoPaSci Schedules (r:1 w:1)
+	// Storage: RoPaSci SchedulesIndex (r:1 w:1)
+	// Storage: RoPaSci ReservedGameIds (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_schedule() -> Weight {
+		(34_870_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RoPaSci SchedulesIndex (r:2 w:2)
+	// Storage: RoPaSci Schedules (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:0)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:2)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	/// The range of component `g` is `[1, 50]`.
+	fn on_initialize_schedules(g: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 58_000
+			.saturating_add((21_906_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Rematches (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:2 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: RoPaSci Spendings (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci RematchesIndex (r:1 w:1)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:0)
+	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci RematchGames (r:0 w:1)
+	// Storage: RoPaSci RevealingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:1)
+	fn rematch() -> Weight {
+		(84_372_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: RoPaSci RematchesIndex (r:1 w:1)
+	// Storage: RoPaSci Rematches (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci ReservedGameIds (r:0 w:1)
+	/// The range of component `g` is `[1, 10000]`.
+	fn on_initialize_rematches(g: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((17_204_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: RoPaSci RevealNonces (r:1 w:1)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci RevealingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: RoPaSci CurrentSeason (r:1 w:0)
+	// Storage: RoPaSci Leaderboard (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:0 w:1)
+	// Storage: RoPaSci GameResultsIndex (r:1 w:1)
+	// Storage: RoPaSci Ratings (r:2 w:2)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:1 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci TeamMembers (r:1 w:1)
+	// Storage: RoPaSci RematchGames (r:1 w:1)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci PlayerStats (r:1 w:1)
+	// Storage: RoPaSci SeasonProfits (r:1 w:1)
+	/// The range of component `r` is `[1, 10000000]`.
+	/// The range of component `p` is `[2, 1000]`.
+	fn reveal_unsigned(r: u32, p: u32, ) -> Weight {
+		(92_000_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
+			// Standard Error: 31_000
+			.saturating_add((18_783_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(21 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci GameAssets (r:1 w:0)
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	fn join_game_with_asset() -> Weight {
+		(84_172_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RoPaSci UnclaimedPayouts (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn claim_payout() -> Weight {
+		(45_318_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RoPaSci AssetRakes (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn withdraw_asset_rake() -> Weight {
+		(44_907_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn claim_prize() -> Weight {
+		(38_642_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: System Account (r:2 w:2)
+	fn fund_house() -> Weight {
+		(41_273_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RoPaSci HouseLiability (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn withdraw_house() -> Weight {
+		(43_118_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RoPaSci HouseDebts (r:1 w:1)
+	// Storage: RoPaSci HouseLiability (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_house_payout() -> Weight {
+		(45_906_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RoPaSci SideBetCounts (r:0 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	/// The range of component `s` is `[1, 100]`.
+	fn settle_side_bets(s: u32, ) -> Weight {
+		(6_918_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((24_611_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(s as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	// Storage: RoPaSci Games (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
 	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
 	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: RoPaSci Spendings (r:1 w:0)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
 	fn start_game() -> Weight {
-		(54_628_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+		(68_435_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
 	// Storage: RoPaSci Games (r:1 w:1)
 	// Storage: RoPaSci Moves (r:1 w:1)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: RoPaSci Spendings (r:1 w:0)
+	// Storage: RoPaSci GameAssets (r:1 w:0)
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
 	fn join_game() -> Weight {
-		(58_212_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+		(66_903_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
 	}
 	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci RevealingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: RoPaSci CurrentSeason (r:1 w:0)
+	// Storage: RoPaSci Leaderboard (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:0 w:1)
+	// Storage: RoPaSci GameResultsIndex (r:1 w:1)
+	// Storage: RoPaSci Ratings (r:2 w:2)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:1 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci TeamMembers (r:1 w:1)
+	// Storage: RoPaSci RematchGames (r:1 w:1)
 	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci PlayerStats (r:1 w:1)
+	// Storage: RoPaSci SeasonProfits (r:1 w:1)
 	/// The range of component `r` is `[1, 10000000]`.
-	fn reveal_move(r: u32, ) -> Weight {
-		(0 as Weight)
+	/// The range of component `p` is `[2, 1000]`.
+	fn reveal_move(r: u32, p: u32, ) -> Weight {
+		(84_000_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			// Standard Error: 31_000
+			.saturating_add((18_783_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(19 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(p as Weight)))
 	}
 	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
 	// Storage: RoPaSci Games (r:1 w:1)
 	// Storage: RoPaSci RevealingGamesIndex (r:2 w:2)
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci System Account (r:1 w:1)
 	/// The range of component `g` is `[1, 10000]`.
 	fn on_initialize_betting(g: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 57_000
 			.saturating_add((12_924_000 as Weight).saturating_mul(g as Weight))
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(g as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(g as Weight)))
 	}
 	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
 	// Storage: RoPaSci RevealingGamesIndex (r:1 w:1)
 	// Storage: RoPaSci Games (r:1 w:1)
 	// Storage: RoPaSci Moves (r:2 w:1)
-	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci ActiveGames (r:2 w:2)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:1 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci TeamMembers (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci CurrentSeason (r:1 w:0)
+	// Storage: RoPaSci PlayerStats (r:2 w:2)
+	// Storage: RoPaSci SeasonProfits (r:2 w:2)
+	// Storage: RoPaSci Leaderboard (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:0 w:1)
+	// Storage: RoPaSci GameResultsIndex (r:1 w:1)
+	// Storage: RoPaSci Ratings (r:2 w:2)
+	// Storage: RoPaSci RematchGames (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
 	/// The range of component `g` is `[1, 10000]`.
 	fn on_initialize_revealing(g: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 449_000
 			.saturating_add((103_314_000 as Weight).saturating_mul(g as Weight))
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((27 as Weight).saturating_mul(g as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((25 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: RoPaSci PalletConfig (r:0 w:1)
+	fn set_config() -> Weight {
+		(14_532_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:0 w:1)
+	fn pause() -> Weight {
+		(12_104_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:0 w:1)
+	fn unpause() -> Weight {
+		(12_257_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: RoPaSci CurrentSeason (r:1 w:0)
+	// Storage: RoPaSci Leaderboard (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:0 w:1)
+	// Storage: RoPaSci GameResultsIndex (r:1 w:1)
+	// Storage: RoPaSci Ratings (r:2 w:2)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:1 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci TeamMembers (r:1 w:1)
+	// Storage: RoPaSci RematchGames (r:1 w:1)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci PlayerStats (r:1 w:1)
+	// Storage: RoPaSci SeasonProfits (r:1 w:1)
+	/// The range of component `p` is `[1, 1000]`.
+	fn force_end(p: u32, ) -> Weight {
+		(98_411_000 as Weight)
+			// Standard Error: 31_000
+			.saturating_add((18_783_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(19 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:0 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:0 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci RematchGames (r:0 w:1)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	/// The range of component `p` is `[1, 1000]`.
+	fn force_refund(p: u32, ) -> Weight {
+		(55_000_000 as Weight)
+			// Standard Error: 19_000
+			.saturating_add((10_825_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: RoPaSci SelfExclusions (r:1 w:1)
+	fn self_exclude() -> Weight {
+		(19_806_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci SpendingCaps (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:1)
+	fn set_spending_cap() -> Weight {
+		(16_391_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	// Storage: RoPaSci GameAssets (r:0 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn start_game_with_asset() -> Weight {
+		(93_560_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn start_game_with_prize() -> Weight {
+		(88_417_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci HouseGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci HouseLiability (r:1 w:1)
+	// Storage: RoPaSci HouseGamesIndex (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn play_house() -> Weight {
+		(71_204_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: RoPaSci HouseGames (r:1 w:1)
+	// Storage: RoPaSci HouseGamesIndex (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci HouseLiability (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	fn reveal_house() -> Weight {
+		(64_871_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: RoPaSci HouseGamesIndex (r:2 w:2)
+	// Storage: RoPaSci HouseGames (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	/// The range of component `g` is `[1, 10000]`.
+	fn on_initialize_house(g: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 63_000
+			.saturating_add((24_518_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(g as Weight)))
 			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(g as Weight)))
 	}
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci GameDeposits (r:1 w:0)
+	// Storage: RoPaSci GameTieBreaks (r:0 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	fn set_tie_break() -> Weight {
+		(24_176_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci GameDeposits (r:1 w:0)
+	// Storage: RoPaSci GameAssets (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci JackpotRollovers (r:0 w:1)
+	fn set_jackpot_rollover() -> Weight {
+		(26_032_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci Moves (r:2 w:0)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci SideBetCounts (r:1 w:1)
+	fn side_bet() -> Weight {
+		(52_417_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci TeamGames (r:0 w:1)
+	// Storage: RoPaSci TeamMembers (r:0 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn start_team_game() -> Weight {
+		(81_339_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	// Storage: RoPaSci TeamMembers (r:4 w:1)
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:0)
+	// Storage: RoPaSci GameAssets (r:1 w:0)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	fn join_team() -> Weight {
+		(67_902_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(18 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:0)
+	fn post_challenge() -> Weight {
+		(54_713_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci ChallengesIndex (r:1 w:1)
+	fn cancel_challenge() -> Weight {
+		(24_107_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci ChallengesIndex (r:2 w:2)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	fn respond_challenge() -> Weight {
+		(57_940_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci ChallengesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:2 w:2)
+	fn reveal_challenge() -> Weight {
+		(62_105_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RoPaSci ChallengesIndex (r:1 w:1)
+	// Storage: RoPaSci Challenges (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:2 w:2)
+	/// The range of component `g` is `[1, 10000]`.
+	fn on_initialize_challenges(g: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 71_000
+			.saturating_add((38_771_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci NextScheduleId (r:1 w:1)
+	// Storage: RoPaSci Schedules (r:0 w:1)
+	// Storage: RoPaSci SchedulesIndex (r:1 w:1)
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn schedule_game() -> Weight {
+		(48_312_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: RoPaSci Schedules (r:1 w:1)
+	// Storage: RoPaSci SchedulesIndex (r:1 w:1)
+	// Storage: RoPaSci ReservedGameIds (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn cancel_schedule() -> Weight {
+		(34_870_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RoPaSci SchedulesIndex (r:2 w:2)
+	// Storage: RoPaSci Schedules (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:0)
+	// Storage: RoPaSci BettingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:2)
+	// Storage: RoPaSci GameDeposits (r:0 w:1)
+	/// The range of component `g` is `[1, 50]`.
+	fn on_initialize_schedules(g: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 58_000
+			.saturating_add((21_906_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: RoPaSci Paused (r:1 w:0)
+	// Storage: RoPaSci Rematches (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:2 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SpendingCaps (r:1 w:0)
+	// Storage: RoPaSci Spendings (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci RematchesIndex (r:1 w:1)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci HouseGames (r:1 w:0)
+	// Storage: RoPaSci Challenges (r:1 w:0)
+	// Storage: RoPaSci Moves (r:0 w:1)
+	// Storage: RoPaSci RematchGames (r:0 w:1)
+	// Storage: RoPaSci RevealingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci PendingSpendingCaps (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:0)
+	// Storage: RoPaSci ReservedGameIds (r:1 w:1)
+	fn rematch() -> Weight {
+		(84_372_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+	}
+	// Storage: RoPaSci RematchesIndex (r:1 w:1)
+	// Storage: RoPaSci Rematches (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci ReservedGameIds (r:0 w:1)
+	/// The range of component `g` is `[1, 10000]`.
+	fn on_initialize_rematches(g: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((17_204_000 as Weight).saturating_mul(g as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(g as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(g as Weight)))
+	}
+	// Storage: RoPaSci RevealNonces (r:1 w:1)
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci RevealingGamesIndex (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	// Storage: RoPaSci SeasonPrizePool (r:1 w:1)
+	// Storage: RoPaSci CurrentSeason (r:1 w:0)
+	// Storage: RoPaSci Leaderboard (r:1 w:1)
+	// Storage: RoPaSci GameResults (r:0 w:1)
+	// Storage: RoPaSci GameResultsIndex (r:1 w:1)
+	// Storage: RoPaSci Ratings (r:2 w:2)
+	// Storage: RoPaSci GameDeposits (r:1 w:1)
+	// Storage: RoPaSci GameAssets (r:1 w:1)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: RoPaSci GameTieBreaks (r:1 w:1)
+	// Storage: RoPaSci JackpotRollovers (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci TeamGames (r:1 w:1)
+	// Storage: RoPaSci TeamMembers (r:1 w:1)
+	// Storage: RoPaSci RematchGames (r:1 w:1)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci PlayerStats (r:1 w:1)
+	// Storage: RoPaSci SeasonProfits (r:1 w:1)
+	/// The range of component `r` is `[1, 10000000]`.
+	/// The range of component `p` is `[2, 1000]`.
+	fn reveal_unsigned(r: u32, p: u32, ) -> Weight {
+		(92_000_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(r as Weight))
+			// Standard Error: 31_000
+			.saturating_add((18_783_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(21 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(20 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((5 as Weight).saturating_mul(p as Weight)))
+	}
+	// Storage: RoPaSci Games (r:1 w:1)
+	// Storage: RoPaSci Moves (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: RoPaSci ActiveGames (r:1 w:1)
+	// Storage: RoPaSci SelfExclusions (r:1 w:0)
+	// Storage: RoPaSci GameAssets (r:1 w:0)
+	// Storage: RoPaSci TeamGames (r:1 w:0)
+	// Storage: RoPaSci PalletConfig (r:1 w:0)
+	fn join_game_with_asset() -> Weight {
+		(84_172_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: RoPaSci UnclaimedPayouts (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn claim_payout() -> Weight {
+		(45_318_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RoPaSci AssetRakes (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn withdraw_asset_rake() -> Weight {
+		(44_907_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RoPaSci Games (r:1 w:0)
+	// Storage: RoPaSci GamePrizes (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	fn claim_prize() -> Weight {
+		(38_642_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: System Account (r:2 w:2)
+	fn fund_house() -> Weight {
+		(41_273_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RoPaSci HouseLiability (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn withdraw_house() -> Weight {
+		(43_118_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: RoPaSci HouseDebts (r:1 w:1)
+	// Storage: RoPaSci HouseLiability (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_house_payout() -> Weight {
+		(45_906_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: RoPaSci SideBetCounts (r:0 w:1)
+	// Storage: RoPaSci SideBets (r:1 w:1)
+	// Storage: RoPaSci Escrow (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RoPaSci Jackpot (r:1 w:1)
+	/// The range of component `s` is `[1, 100]`.
+	fn settle_side_bets(s: u32, ) -> Weight {
+		(6_918_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((24_611_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(s as Weight)))
+	}
 }